@@ -25,16 +25,16 @@ find "$dir" -type f -print | while read file
 do
     case $(uname) in
     Darwin)
-        sed -i '' "s/\$day/$day/g" "$file"
         sed -i '' "s/\$daypad/$daypad/g" "$file"
+        sed -i '' "s/\$day/$day/g" "$file"
         sed -i '' "s/\$dir/$dir/g" "$file"
         ;;
     *)
-        sed -i "s/\$day/$day/g" "$file"
         sed -i "s/\$daypad/$daypad/g" "$file"
+        sed -i "s/\$day/$day/g" "$file"
         sed -i "s/\$dir/$dir/g" "$file"
         ;;
     esac
 done
 
-echo "!!! Add to main Cargo.toml !!!"
\ No newline at end of file
+echo "!!! Add to main Cargo.toml and the runner days table !!!"
\ No newline at end of file
@@ -1,6 +1,6 @@
 #!/bin/bash
 
-for day in $(seq 1 25)
-do
-    ./RunRel.sh $day
-done
+# Benchmark all days, writing results to the stats directory
+os="$(uname | tr '[:upper:]' '[:lower:]' | sed 's/darwin/macos/')"
+
+cargo run --release --bin aoc -- bench all --json "stats/bench-$os.json" --csv "stats/bench-$os.csv"
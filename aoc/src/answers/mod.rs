@@ -0,0 +1,184 @@
+use std::{error::Error, fmt, fs};
+
+use crate::{
+    input::parse_input,
+    solution::{Answer, DaySolution},
+};
+
+/// Outcome of checking one part's answer against the answers store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// Answer matches the stored answer
+    Pass,
+    /// Answer differs from the stored answer
+    Fail(Answer),
+    /// No answer is stored for the part
+    Missing,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("pass"),
+            Verdict::Fail(expected) => write!(f, "FAIL (expected {expected})"),
+            Verdict::Missing => f.write_str("missing"),
+        }
+    }
+}
+
+/// Loads the stored answers for a day. Returns an empty list if there is no answers file
+pub fn load_answers(day: usize) -> Result<Vec<Option<Answer>>, Box<dyn Error>> {
+    let file = format!("day{day:02}.toml");
+
+    let text = match fs::read_to_string(format!("answers/{file}")) {
+        Err(_) => fs::read_to_string(format!("../answers/{file}")),
+        t => t,
+    };
+
+    match text {
+        Ok(text) => parse_answers(&text).map_err(|e| format!("{file}: {e}").into()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e)?,
+    }
+}
+
+/// Parses an answers file. Each answer is a `partN = value` line where the value
+/// is an integer, a string or an array of strings for multi-line answers
+pub fn parse_answers(text: &str) -> Result<Vec<Option<Answer>>, Box<dyn Error>> {
+    let mut answers = Vec::new();
+
+    for (lineno, line) in text.lines().enumerate() {
+        let line = line.trim();
+
+        // Skip blank lines and comments
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let err = |msg: &str| format!("line {}: {msg}", lineno + 1);
+
+        // Split key and value
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| err("expected key = value"))?;
+
+        let part = key
+            .trim()
+            .strip_prefix("part")
+            .and_then(|n| n.parse::<usize>().ok())
+            .filter(|&n| n > 0)
+            .ok_or_else(|| err("expected key partN"))?;
+
+        let answer = parse_value(value.trim()).ok_or_else(|| err("invalid value"))?;
+
+        // Store the answer
+        if answers.len() < part {
+            answers.resize(part, None);
+        }
+
+        answers[part - 1] = Some(answer);
+    }
+
+    Ok(answers)
+}
+
+/// Formats answers in the answers file format
+pub fn format_answers(answers: &[Answer]) -> String {
+    let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+
+    answers
+        .iter()
+        .enumerate()
+        .map(|(part, answer)| {
+            let value = match answer {
+                Answer::Int(n) => n.to_string(),
+                Answer::Str(s) => quote(s),
+                Answer::Lines(lines) => format!(
+                    "[{}]",
+                    lines
+                        .iter()
+                        .map(|l| quote(l))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            };
+
+            format!("part{} = {value}\n", part + 1)
+        })
+        .collect()
+}
+
+/// Solves a day against its input and checks each part against the stored answers
+pub fn verify(solution: &dyn DaySolution) -> Result<Vec<(Answer, Verdict)>, Box<dyn Error>> {
+    let expected = load_answers(solution.day())?;
+    let answers = parse_input(solution.day(), |input| solution.solve(input))??;
+
+    Ok(answers
+        .into_iter()
+        .enumerate()
+        .map(|(part, answer)| {
+            let verdict = match expected.get(part) {
+                Some(Some(expected)) if *expected == answer => Verdict::Pass,
+                Some(Some(expected)) => Verdict::Fail(expected.clone()),
+                _ => Verdict::Missing,
+            };
+
+            (answer, verdict)
+        })
+        .collect())
+}
+
+fn parse_value(value: &str) -> Option<Answer> {
+    if let Some(list) = value.strip_prefix('[') {
+        // Array of strings
+        let mut rest = list.strip_suffix(']')?.trim();
+        let mut lines = Vec::new();
+
+        while !rest.is_empty() {
+            let (line, remain) = parse_string(rest)?;
+            lines.push(line);
+
+            rest = remain.trim_start();
+
+            if let Some(remain) = rest.strip_prefix(',') {
+                rest = remain.trim_start();
+            } else if !rest.is_empty() {
+                return None;
+            }
+        }
+
+        Some(Answer::Lines(lines))
+    } else if value.starts_with('"') {
+        // String
+        match parse_string(value)? {
+            (s, "") => Some(Answer::Str(s)),
+            _ => None,
+        }
+    } else {
+        // Integer
+        value.parse::<u64>().ok().map(Answer::Int)
+    }
+}
+
+/// Parses a quoted string, returning the string and the remaining text
+fn parse_string(value: &str) -> Option<(String, &str)> {
+    let mut result = String::new();
+    let mut chars = value.strip_prefix('"')?.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((result, &value[i + 2..])),
+            '\\' => match chars.next()?.1 {
+                '\\' => result.push('\\'),
+                '"' => result.push('"'),
+                _ => return None,
+            },
+            _ => result.push(c),
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests;
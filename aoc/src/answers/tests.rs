@@ -0,0 +1,33 @@
+use super::*;
+
+#[test]
+fn test_round_trip() {
+    let answers = vec![
+        Answer::Int(99460),
+        Answer::Str("bg,\"bu\"\\ce".to_string()),
+        Answer::Lines(vec!["#..#".to_string(), "".to_string(), "x, y".to_string()]),
+    ];
+
+    let text = format_answers(&answers);
+
+    assert_eq!(
+        parse_answers(&text).unwrap(),
+        answers.into_iter().map(Some).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_parse() {
+    let text = "# Day 25\n\npart2 = \"46,28\"\n";
+
+    assert_eq!(
+        parse_answers(text).unwrap(),
+        vec![None, Some(Answer::Str("46,28".to_string()))]
+    );
+
+    assert!(parse_answers("part0 = 1").is_err());
+    assert!(parse_answers("part1 = -1").is_err());
+    assert!(parse_answers("part1 = \"abc").is_err());
+    assert!(parse_answers("part1 = \"a\" b").is_err());
+    assert!(parse_answers("part1 = [\"a\" \"b\"]").is_err());
+}
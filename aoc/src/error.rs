@@ -0,0 +1,102 @@
+use std::{fmt, io, path::PathBuf};
+
+/// Errors returned when reading and parsing puzzle input
+#[derive(Debug)]
+pub enum Error {
+    /// The input file does not exist
+    MissingFile(PathBuf),
+    /// Some other I/O error occurred reading the input
+    Io(io::Error),
+    /// The input contains no lines
+    EmptyInput,
+    /// The input is not valid UTF-8
+    InvalidUtf8 { line: usize, column: usize },
+    /// The input could not be parsed. Line and column are 1 based, line is 0 if unknown
+    Parse {
+        line: usize,
+        column: Option<usize>,
+        message: String,
+    },
+}
+
+impl Error {
+    /// Creates a parse error with no location
+    pub fn parse(message: impl Into<String>) -> Self {
+        Error::Parse {
+            line: 0,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Creates a parse error at a column of the line being parsed
+    pub fn parse_at(column: usize, message: impl Into<String>) -> Self {
+        Error::Parse {
+            line: 0,
+            column: Some(column),
+            message: message.into(),
+        }
+    }
+
+    /// Sets the line number of a parse error
+    pub fn at_line(self, line: usize) -> Self {
+        match self {
+            Error::Parse {
+                column, message, ..
+            } => Error::Parse {
+                line,
+                column,
+                message,
+            },
+            e => e,
+        }
+    }
+
+    /// Converts an error returned by a line transform to a parse error at a line
+    pub(crate) fn from_transform(e: Box<dyn std::error::Error>, line: usize) -> Self {
+        match e.downcast::<Error>() {
+            Ok(e) => e.at_line(line),
+            Err(e) => Error::parse(e.to_string()).at_line(line),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingFile(path) => write!(f, "Input file {} not found", path.display()),
+            Error::Io(e) => write!(f, "Error reading input: {e}"),
+            Error::EmptyInput => f.write_str("Input is empty"),
+            Error::InvalidUtf8 { line, column } => {
+                write!(f, "Invalid UTF-8 at line {line}, column {column}")
+            }
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => match (line, column) {
+                (0, None) => write!(f, "Parse error: {message}"),
+                (0, Some(column)) => write!(f, "Parse error at column {column}: {message}"),
+                (line, None) => write!(f, "Parse error at line {line}: {message}"),
+                (line, Some(column)) => {
+                    write!(f, "Parse error at line {line}, column {column}: {message}")
+                }
+            },
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
@@ -0,0 +1,209 @@
+use std::ops::{Add, Sub};
+
+/// Two dimensional coordinate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Coord<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Coord<T> {
+    /// Creates a coordinate
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<(T, T)> for Coord<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Coord<T>> for (T, T) {
+    fn from(c: Coord<T>) -> Self {
+        (c.x, c.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Coord<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Coord<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty, $s:ty);*) => {$(
+        impl Coord<$t> {
+            /// Returns the Manhattan distance to another coordinate
+            pub fn manhattan(&self, other: &Self) -> $t {
+                self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+            }
+
+            /// Adds a signed offset, returning None if either axis goes below zero or overflows
+            pub fn checked_add(&self, offset: Coord<$s>) -> Option<Self> {
+                Some(Self::new(
+                    self.x.checked_add_signed(offset.x)?,
+                    self.y.checked_add_signed(offset.y)?,
+                ))
+            }
+
+            /// Adds a signed offset, returning None if the result is outside of (0, 0) to bounds (exclusive)
+            pub fn checked_add_bounded(&self, offset: Coord<$s>, bounds: Self) -> Option<Self> {
+                self.checked_add(offset)
+                    .filter(|c| c.x < bounds.x && c.y < bounds.y)
+            }
+
+            /// Adds a signed offset, wrapping around within (0, 0) to bounds (exclusive)
+            pub fn add_mod(&self, offset: Coord<$s>, bounds: Self) -> Self {
+                // Use a wider type so neither the sum nor the bounds can overflow
+                Self::new(
+                    (self.x as i128 + offset.x as i128).rem_euclid(bounds.x as i128) as $t,
+                    (self.y as i128 + offset.y as i128).rem_euclid(bounds.y as i128) as $t,
+                )
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed {
+    ($($t:ty, $u:ty);*) => {$(
+        impl Coord<$t> {
+            /// Returns the Manhattan distance to another coordinate
+            pub fn manhattan(&self, other: &Self) -> $u {
+                self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+            }
+
+            /// Adds an offset, returning None on overflow
+            pub fn checked_add(&self, offset: Self) -> Option<Self> {
+                Some(Self::new(
+                    self.x.checked_add(offset.x)?,
+                    self.y.checked_add(offset.y)?,
+                ))
+            }
+
+            /// Adds an offset, wrapping around within (0, 0) to bounds (exclusive)
+            pub fn add_mod(&self, offset: Self, bounds: Self) -> Self {
+                // Use a wider type so the sum can't overflow
+                Self::new(
+                    (self.x as i128 + offset.x as i128).rem_euclid(bounds.x as i128) as $t,
+                    (self.y as i128 + offset.y as i128).rem_euclid(bounds.y as i128) as $t,
+                )
+            }
+        }
+    )*};
+}
+
+impl_unsigned!(usize, isize; u64, i64; u32, i32; u16, i16; u8, i8);
+impl_signed!(isize, usize; i64, u64; i32, u32; i16, u16; i8, u8);
+
+/// Four way direction. North is towards y = 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Dir4 {
+    /// All directions, clockwise from north
+    pub const ALL: [Dir4; 4] = [Dir4::N, Dir4::E, Dir4::S, Dir4::W];
+
+    /// Returns the direction rotated 90 degrees clockwise
+    pub fn rotate_right(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 4]
+    }
+
+    /// Returns the direction rotated 90 degrees anticlockwise
+    pub fn rotate_left(&self) -> Self {
+        Self::ALL[(*self as usize + 3) % 4]
+    }
+
+    /// Returns the opposite direction
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(*self as usize + 2) % 4]
+    }
+
+    /// Returns the coordinate offset of a step in the direction
+    pub fn offset<T: From<i8>>(&self) -> Coord<T> {
+        Dir8::from(*self).offset()
+    }
+}
+
+/// Eight way direction. North is towards y = 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// All directions, clockwise from north
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    /// Returns the direction rotated 45 degrees clockwise
+    pub fn rotate_right(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 8]
+    }
+
+    /// Returns the direction rotated 45 degrees anticlockwise
+    pub fn rotate_left(&self) -> Self {
+        Self::ALL[(*self as usize + 7) % 8]
+    }
+
+    /// Returns the opposite direction
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(*self as usize + 4) % 8]
+    }
+
+    /// Returns the coordinate offset of a step in the direction
+    pub fn offset<T: From<i8>>(&self) -> Coord<T> {
+        let (x, y) = match self {
+            Dir8::N => (0, -1),
+            Dir8::NE => (1, -1),
+            Dir8::E => (1, 0),
+            Dir8::SE => (1, 1),
+            Dir8::S => (0, 1),
+            Dir8::SW => (-1, 1),
+            Dir8::W => (-1, 0),
+            Dir8::NW => (-1, -1),
+        };
+
+        Coord::new(T::from(x), T::from(y))
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests;
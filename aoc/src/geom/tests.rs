@@ -0,0 +1,67 @@
+use super::*;
+
+#[test]
+fn test_coord() {
+    let a = Coord::new(3usize, 4);
+    let b = Coord::new(7usize, 1);
+
+    assert_eq!(a.manhattan(&b), 7);
+    assert_eq!(a + b, Coord::new(10, 5));
+    assert_eq!(Coord::from((1, 2)), Coord::new(1, 2));
+
+    assert_eq!(a.checked_add(Coord::new(-3, -4)), Some(Coord::new(0, 0)));
+    assert_eq!(a.checked_add(Coord::new(-4, 0)), None);
+    assert_eq!(
+        a.checked_add_bounded(Coord::new(1, 0), Coord::new(5, 5)),
+        Some(Coord::new(4, 4))
+    );
+    assert_eq!(
+        a.checked_add_bounded(Coord::new(2, 0), Coord::new(5, 5)),
+        None
+    );
+
+    assert_eq!(
+        a.add_mod(Coord::new(-5, 3), Coord::new(11, 7)),
+        Coord::new(9, 0)
+    );
+
+    // Values above the signed maximum of the same width
+    let b = Coord::new(200u8, 130);
+    assert_eq!(
+        b.add_mod(Coord::new(100, -3), Coord::new(250, 131)),
+        Coord::new(50, 127)
+    );
+    assert_eq!(
+        Coord::new(40000u16, 0).add_mod(Coord::new(1, 1), Coord::new(40001, 2)),
+        Coord::new(0, 1)
+    );
+    assert_eq!(
+        Coord::new(100i8, 0).add_mod(Coord::new(100, 0), Coord::new(127, 1)),
+        Coord::new(73, 0)
+    );
+
+    let c = Coord::new(-2i64, 3);
+    assert_eq!(c.manhattan(&Coord::new(1, -1)), 7u64);
+    assert_eq!(
+        c.add_mod(Coord::new(0, 5), Coord::new(4, 4)),
+        Coord::new(2, 0)
+    );
+}
+
+#[test]
+fn test_dir4() {
+    assert_eq!(Dir4::N.rotate_right(), Dir4::E);
+    assert_eq!(Dir4::N.rotate_left(), Dir4::W);
+    assert_eq!(Dir4::E.opposite(), Dir4::W);
+    assert_eq!(Dir4::S.offset::<isize>(), Coord::new(0, 1));
+    assert_eq!(Dir4::W.offset::<i64>(), Coord::new(-1, 0));
+}
+
+#[test]
+fn test_dir8() {
+    assert_eq!(Dir8::NW.rotate_right(), Dir8::N);
+    assert_eq!(Dir8::N.rotate_left(), Dir8::NW);
+    assert_eq!(Dir8::NE.opposite(), Dir8::SW);
+    assert_eq!(Dir8::SW.offset::<isize>(), Coord::new(-1, 1));
+    assert_eq!(Dir8::from(Dir4::S), Dir8::S);
+}
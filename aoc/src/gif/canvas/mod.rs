@@ -0,0 +1,310 @@
+use std::ops::{Index, IndexMut};
+
+use super::font::render;
+use crate::geom::Dir4;
+
+/// Frame of palette indexes stored in a flat vector. Drawing operations take signed
+/// positions and are clipped to the canvas
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    /// Creates a canvas filled with colour 0
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::filled(width, height, 0u8)
+    }
+
+    /// Creates a canvas filled with a colour
+    pub fn filled(width: usize, height: usize, colour: impl Into<u8>) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![colour.into(); width * height],
+        }
+    }
+
+    /// Creates a canvas from rows of pixels. All rows must be the same length
+    pub fn from_rows(rows: &[Vec<u8>]) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Canvas rows must all be the same length"
+        );
+
+        Self {
+            width,
+            height,
+            pixels: rows.concat(),
+        }
+    }
+
+    /// Returns the width of the canvas
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the canvas
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the pixels in row order
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Returns a row of pixels
+    pub fn row(&self, y: usize) -> &[u8] {
+        &self.pixels[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns an iterator over the rows of pixels
+    pub fn rows(&self) -> impl Iterator<Item = &[u8]> {
+        self.pixels.chunks_exact(self.width.max(1))
+    }
+
+    /// Returns the pixels as a vector of rows
+    pub fn to_rows(&self) -> Vec<Vec<u8>> {
+        self.rows().map(|row| row.to_vec()).collect()
+    }
+
+    /// Returns the colour at a position, or None if it is off the canvas
+    pub fn get(&self, x: isize, y: isize) -> Option<u8> {
+        self.clip(x, y).map(|pos| self[pos])
+    }
+
+    /// Fills the whole canvas with a colour
+    pub fn fill(&mut self, colour: impl Into<u8>) {
+        self.pixels.fill(colour.into());
+    }
+
+    /// Sets the colour of a pixel
+    pub fn set(&mut self, x: isize, y: isize, colour: impl Into<u8>) {
+        if let Some(pos) = self.clip(x, y) {
+            self[pos] = colour.into();
+        }
+    }
+
+    /// Fills a rectangle with its top left corner at a given position
+    pub fn fill_rect(
+        &mut self,
+        x: isize,
+        y: isize,
+        width: usize,
+        height: usize,
+        colour: impl Into<u8>,
+    ) {
+        let colour = colour.into();
+
+        // Clip the rectangle to the canvas
+        let x1 = x.clamp(0, self.width as isize) as usize;
+        let y1 = y.clamp(0, self.height as isize) as usize;
+        let x2 = x
+            .saturating_add_unsigned(width)
+            .clamp(0, self.width as isize) as usize;
+        let y2 = y
+            .saturating_add_unsigned(height)
+            .clamp(0, self.height as isize) as usize;
+
+        for y in y1..y2 {
+            self.pixels[y * self.width + x1..y * self.width + x2].fill(colour);
+        }
+    }
+
+    /// Draws a line between two points inclusive using Bresenham's algorithm.
+    /// Only the steps of the line which fall on the canvas are walked
+    pub fn line(
+        &mut self,
+        (x1, y1): (isize, isize),
+        (x2, y2): (isize, isize),
+        colour: impl Into<u8>,
+    ) {
+        let colour = colour.into();
+
+        // Split the line into the major axis, which steps every pixel, and the minor axis
+        let x = Axis::new(x1, x2, self.width);
+        let y = Axis::new(y1, y2, self.height);
+        let x_major = x.delta >= y.delta;
+        let (major, minor) = if x_major { (x, y) } else { (y, x) };
+
+        // Minor axis offset after a number of major axis steps
+        let offset = |i: i128| {
+            if major.delta == 0 {
+                0
+            } else {
+                (2 * i * minor.delta + major.delta).div_euclid(2 * major.delta)
+            }
+        };
+
+        // Clip the major axis steps to the canvas
+        let (lo, hi) = major.steps();
+        let (mut lo, mut hi) = (lo.max(0), hi.min(major.delta));
+
+        // Clip the major axis steps further to keep the minor axis on the canvas
+        let (mlo, mhi) = minor.steps();
+        let (mlo, mhi) = (mlo.max(0), mhi.min(minor.delta));
+
+        if mlo > mhi {
+            return;
+        }
+
+        // Solve mlo <= offset(i) <= mhi for i, rounding the lower bound up
+        if minor.delta > 0 {
+            let div = 2 * minor.delta;
+            lo = lo.max(-(major.delta - 2 * major.delta * mlo).div_euclid(div));
+            hi = hi.min((2 * major.delta * (mhi + 1) - major.delta - 1).div_euclid(div));
+        }
+
+        for i in lo..=hi {
+            let a = (major.start + major.step * i) as isize;
+            let b = (minor.start + minor.step * offset(i)) as isize;
+
+            if x_major {
+                self.set(a, b, colour);
+            } else {
+                self.set(b, a, colour);
+            }
+        }
+    }
+
+    /// Replaces the area of one colour joined orthogonally to a position with another colour
+    pub fn flood_fill(&mut self, x: isize, y: isize, colour: impl Into<u8>) {
+        let colour = colour.into();
+
+        let Some(start) = self.clip(x, y) else {
+            return;
+        };
+
+        let target = self[start];
+
+        if target == colour {
+            return;
+        }
+
+        // Fill from a stack of positions to visit
+        let mut stack = vec![start];
+        self[start] = colour;
+
+        while let Some((x, y)) = stack.pop() {
+            for dir in Dir4::ALL {
+                let offset = dir.offset::<isize>();
+
+                if let Some(pos) = self.clip(x as isize + offset.x, y as isize + offset.y) {
+                    if self[pos] == target {
+                        self[pos] = colour;
+                        stack.push(pos);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Copies another canvas with its top left corner at a given position. Pixels of the
+    /// transparent colour are skipped if given
+    pub fn blit(&mut self, src: &Canvas, x: isize, y: isize, transparent: Option<u8>) {
+        for (sy, row) in src.rows().enumerate() {
+            let Some(dy) = self.clip_y(y + sy as isize) else {
+                continue;
+            };
+
+            for (sx, &pix) in row.iter().enumerate() {
+                if Some(pix) == transparent {
+                    continue;
+                }
+
+                if let Some(dx) = self.clip_x(x + sx as isize) {
+                    self[(dx, dy)] = pix;
+                }
+            }
+        }
+    }
+
+    /// Draws text with its top left corner at a given position
+    pub fn draw_text(&mut self, x: isize, y: isize, colour: impl Into<u8>, text: &str) {
+        let colour = colour.into();
+
+        render(text, 1, |px, py| {
+            self.set(x + px as isize, y + py as isize, colour)
+        });
+    }
+
+    /// Converts a signed position to a position on the canvas
+    fn clip(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        Some((self.clip_x(x)?, self.clip_y(y)?))
+    }
+
+    /// Converts a signed x position to a column on the canvas
+    fn clip_x(&self, x: isize) -> Option<usize> {
+        usize::try_from(x).ok().filter(|&x| x < self.width)
+    }
+
+    /// Converts a signed y position to a row on the canvas
+    fn clip_y(&self, y: isize) -> Option<usize> {
+        usize::try_from(y).ok().filter(|&y| y < self.height)
+    }
+}
+
+/// One axis of a line being drawn
+#[derive(Clone, Copy)]
+struct Axis {
+    start: i128,
+    step: i128,
+    delta: i128,
+    len: i128,
+}
+
+impl Axis {
+    fn new(start: isize, end: isize, len: usize) -> Self {
+        let (start, end) = (start as i128, end as i128);
+
+        Self {
+            start,
+            step: (end - start).signum(),
+            delta: (end - start).abs(),
+            len: len as i128,
+        }
+    }
+
+    /// Returns the inclusive range of steps which are on the canvas, which may be empty
+    fn steps(&self) -> (i128, i128) {
+        match self.step {
+            1 => (-self.start, self.len - 1 - self.start),
+            -1 => (self.start - self.len + 1, self.start),
+            _ if (0..self.len).contains(&self.start) => (0, 0),
+            _ => (1, 0),
+        }
+    }
+}
+
+impl From<Vec<Vec<u8>>> for Canvas {
+    fn from(rows: Vec<Vec<u8>>) -> Self {
+        Self::from_rows(&rows)
+    }
+}
+
+impl Index<(usize, usize)> for Canvas {
+    type Output = u8;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(x < self.width, "Position {:?} out of bounds", (x, y));
+
+        &self.pixels[y * self.width + x]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Canvas {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(x < self.width, "Position {:?} out of bounds", (x, y));
+
+        &mut self.pixels[y * self.width + x]
+    }
+}
+
+#[cfg(test)]
+mod tests;
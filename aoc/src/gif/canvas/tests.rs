@@ -0,0 +1,118 @@
+use super::*;
+
+fn rows(canvas: &Canvas) -> Vec<String> {
+    canvas
+        .rows()
+        .map(|row| row.iter().map(|p| char::from(b'0' + p)).collect())
+        .collect()
+}
+
+#[test]
+fn test_set() {
+    let mut canvas = Canvas::new(3, 2);
+
+    canvas.set(0, 0, 1);
+    canvas.set(2, 1, 2);
+    canvas[(1, 1)] = 3;
+
+    // Off the canvas
+    canvas.set(-1, 0, 4);
+    canvas.set(3, 0, 4);
+    canvas.set(0, 2, 4);
+
+    assert_eq!(rows(&canvas), vec!["100", "032"]);
+    assert_eq!(canvas.get(2, 1), Some(2));
+    assert_eq!(canvas.get(-1, 1), None);
+    assert_eq!(canvas.to_rows(), vec![vec![1, 0, 0], vec![0, 3, 2]]);
+    assert_eq!(Canvas::from(canvas.to_rows()), canvas);
+}
+
+#[test]
+fn test_fill_rect() {
+    let mut canvas = Canvas::new(4, 3);
+
+    canvas.fill_rect(1, 1, 2, 1, 1);
+    canvas.fill_rect(-2, -2, 3, 3, 2);
+    canvas.fill_rect(3, 2, 10, 10, 3);
+    canvas.fill_rect(5, 0, 1, 1, 4);
+
+    assert_eq!(rows(&canvas), vec!["2000", "0110", "0003"]);
+}
+
+#[test]
+fn test_line() {
+    let mut canvas = Canvas::new(5, 3);
+
+    canvas.line((0, 0), (4, 2), 1);
+    canvas.line((4, 0), (4, 0), 2);
+    canvas.line((-2, 2), (1, 2), 3);
+
+    assert_eq!(rows(&canvas), vec!["10002", "01100", "33011"]);
+
+    // Reversed lines cover the same number of pixels
+    let mut reversed = Canvas::new(5, 3);
+    reversed.line((4, 2), (0, 0), 1);
+
+    assert_eq!(reversed.pixels().iter().filter(|&&p| p == 1).count(), 5);
+}
+
+#[test]
+fn test_flood_fill() {
+    let mut canvas = Canvas::from_rows(&[vec![0, 0, 1, 0], vec![1, 1, 1, 0], vec![0, 0, 1, 0]]);
+
+    canvas.flood_fill(3, 0, 2);
+    assert_eq!(rows(&canvas), vec!["0012", "1112", "0012"]);
+
+    // Diagonals are not joined
+    canvas.flood_fill(0, 0, 3);
+    assert_eq!(rows(&canvas), vec!["3312", "1112", "0012"]);
+
+    canvas.flood_fill(9, 9, 3);
+    canvas.flood_fill(0, 2, 0);
+    assert_eq!(rows(&canvas), vec!["3312", "1112", "0012"]);
+}
+
+#[test]
+fn test_blit() {
+    let sprite = Canvas::from_rows(&[vec![1, 0], vec![2, 1]]);
+    let mut canvas = Canvas::filled(3, 3, 5);
+
+    canvas.blit(&sprite, 0, 0, None);
+    canvas.blit(&sprite, 2, 2, Some(0));
+    canvas.blit(&sprite, -1, 1, Some(0));
+
+    assert_eq!(rows(&canvas), vec!["105", "215", "151"]);
+}
+
+#[test]
+fn test_draw_text() {
+    let mut canvas = Canvas::new(5, 5);
+
+    canvas.draw_text(-1, 0, 1, "7");
+    canvas.draw_text(3, 1, 2, "-");
+
+    assert_eq!(
+        rows(&canvas),
+        vec!["11000", "01000", "01000", "10022", "10000"]
+    );
+}
+
+#[test]
+fn test_line_clipped() {
+    // Long lines mostly off the canvas only draw the pixels on it
+    let mut canvas = Canvas::new(3, 3);
+
+    canvas.line((-1_000_000_000_000, 1), (1_000_000_000_000, 1), 1);
+    canvas.line((2, isize::MIN / 4), (2, isize::MAX / 4), 2);
+    canvas.line(
+        (-4_000_000_000, -4_000_000_000),
+        (4_000_000_000, 4_000_000_000),
+        3,
+    );
+    canvas.line((-1_000_000, 5), (1_000_000, 5), 4);
+
+    assert_eq!(
+        canvas.to_rows(),
+        vec![vec![3, 0, 2], vec![1, 3, 2], vec![0, 0, 3]]
+    );
+}
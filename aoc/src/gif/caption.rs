@@ -0,0 +1,51 @@
+use super::font::{render, GLYPH_HEIGHT};
+
+/// Status bar below the animation for text captions. The bar is drawn at the output
+/// resolution so is not affected by the frame scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CaptionBar {
+    /// Number of lines of text
+    pub lines: u16,
+    /// Text scale
+    pub scale: u16,
+    /// Text colour
+    pub colour: u8,
+    /// Bar colour
+    pub background: u8,
+}
+
+impl CaptionBar {
+    /// Creates a caption bar with a number of lines of unscaled text
+    pub fn new(lines: u16, colour: impl Into<u8>, background: impl Into<u8>) -> Self {
+        Self {
+            lines,
+            scale: 1,
+            colour: colour.into(),
+            background: background.into(),
+        }
+    }
+
+    /// Returns the height of the bar in pixels, with a one pixel border at the text scale
+    pub fn height(&self) -> u16 {
+        ((self.lines * (GLYPH_HEIGHT as u16 + 1)) + 1) * self.scale
+    }
+
+    /// Returns the pixels of the bar showing some text. Lines past the end of the bar are
+    /// not shown
+    pub(crate) fn draw(&self, width: u16, text: &str) -> Vec<u8> {
+        let (width, height) = (width as usize, self.height() as usize);
+        let scale = self.scale as usize;
+
+        let mut pixels = vec![self.background; width * height];
+
+        render(text, scale, |x, y| {
+            let (x, y) = (x + scale, y + scale);
+
+            if x < width && y < height - scale {
+                pixels[(y * width) + x] = self.colour;
+            }
+        });
+
+        pixels
+    }
+}
@@ -0,0 +1,54 @@
+use std::error::Error;
+
+use gif::{ColorOutput, DecodeOptions, DisposalMethod};
+
+/// Full image after a GIF frame has been applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedFrame {
+    pub pixels: Vec<Vec<u8>>,
+    pub delay: u16,
+}
+
+/// Decodes a GIF into the palette indexed image shown after each frame, for checking
+/// animations in tests
+pub fn decode_gif(data: &[u8]) -> Result<Vec<DecodedFrame>, Box<dyn Error>> {
+    let mut options = DecodeOptions::new();
+    options.set_color_output(ColorOutput::Indexed);
+
+    let mut decoder = options.read_info(data)?;
+
+    let mut screen = vec![vec![0; decoder.width() as usize]; decoder.height() as usize];
+    let mut frames = Vec::new();
+
+    while let Some(frame) = decoder.read_next_frame()? {
+        let (left, top) = (frame.left as usize, frame.top as usize);
+        let previous = screen.clone();
+
+        // Draw the frame's opaque pixels
+        for (y, row) in frame.buffer.chunks_exact(frame.width as usize).enumerate() {
+            for (x, &pix) in row.iter().enumerate() {
+                if frame.transparent != Some(pix) {
+                    screen[top + y][left + x] = pix;
+                }
+            }
+        }
+
+        frames.push(DecodedFrame {
+            pixels: screen.clone(),
+            delay: frame.delay,
+        });
+
+        // Dispose of the frame before the next one
+        match frame.dispose {
+            DisposalMethod::Any | DisposalMethod::Keep => (),
+            DisposalMethod::Background => {
+                for row in &mut screen[top..top + frame.height as usize] {
+                    row[left..left + frame.width as usize].fill(0);
+                }
+            }
+            DisposalMethod::Previous => screen = previous,
+        }
+    }
+
+    Ok(frames)
+}
@@ -0,0 +1,50 @@
+use super::*;
+
+#[test]
+fn test_draw_text() {
+    let mut frame = vec![vec![0; 8]; 5];
+
+    draw_text(&mut frame, 0, 0, 1, "1t");
+
+    assert_eq!(
+        frame,
+        vec![
+            vec![0, 1, 0, 0, 1, 1, 1, 0],
+            vec![1, 1, 0, 0, 0, 1, 0, 0],
+            vec![0, 1, 0, 0, 0, 1, 0, 0],
+            vec![0, 1, 0, 0, 0, 1, 0, 0],
+            vec![1, 1, 1, 0, 0, 1, 0, 0],
+        ]
+    );
+}
+
+#[test]
+fn test_clipping() {
+    let mut frame = vec![vec![0; 2]; 2];
+
+    // Glyphs after a space and on the next line are outside the frame
+    draw_text(&mut frame, 0, 0, 2, " 8\n8");
+
+    assert_eq!(frame, vec![vec![0, 0], vec![0, 0]]);
+
+    // Only the top left of the glyph is in the frame
+    draw_text(&mut frame, 1, 1, 2, "7");
+
+    assert_eq!(frame, vec![vec![0, 0], vec![0, 2]]);
+}
+
+#[test]
+fn test_text_size() {
+    assert_eq!(text_size(""), (0, 0));
+    assert_eq!(text_size("A"), (3, 5));
+    assert_eq!(text_size("ABC\nD"), (11, 11));
+}
+
+#[test]
+fn test_scaled() {
+    let mut pixels = Vec::new();
+
+    render(".", 2, |x, y| pixels.push((x, y)));
+
+    assert_eq!(pixels, vec![(2, 8), (3, 8), (2, 9), (3, 9)]);
+}
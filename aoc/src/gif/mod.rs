@@ -1,20 +1,50 @@
-use std::{borrow::Cow, cmp::max, error::Error, fs::File};
+use std::{error::Error, io::Write};
 
-use gif::{Encoder, Frame, Repeat};
-
-use self::region::Region;
+use self::region::{DirtyRegions, Region};
+pub use self::{
+    canvas::Canvas,
+    caption::CaptionBar,
+    decode::{decode_gif, DecodedFrame},
+    font::{draw_text, text_size, GLYPH_HEIGHT, GLYPH_WIDTH},
+    palette::{Colour, Palette, PaletteFull, Ramp, HSL},
+    sink::{ApngSink, Format, FrameSink, GifSink, PngDirSink, SubFrame, TermSink, Y4mSink},
+};
 
+mod canvas;
+mod caption;
+mod decode;
+mod font;
+mod palette;
 mod region;
+mod sink;
 
-pub struct Gif {
+/// Scaled animation written to a frame sink, GIF by default
+pub struct Gif<S: FrameSink = Box<dyn FrameSink>> {
     width: u16,
     height: u16,
     x_scale: u16,
     y_scale: u16,
     gif_width: u16,
     gif_height: u16,
-    encoder: Encoder<File>,
-    last_frame: Option<Vec<Vec<u8>>>,
+    sink: S,
+    last_frame: Option<Canvas>,
+    split_regions: bool,
+    transparent_deltas: bool,
+    caption: Option<CaptionBar>,
+    caption_text: String,
+    last_caption: Option<String>,
+    max_frames: Option<usize>,
+    frames: usize,
+}
+
+/// Scaled pixels to be written as a sub-frame
+struct Section {
+    left: u16,
+    top: u16,
+    width: u16,
+    height: u16,
+    pixels: Vec<u8>,
+    transparent: Option<u8>,
 }
 
 impl Gif {
@@ -27,34 +57,164 @@ impl Gif {
         x_scale: u16,
         y_scale: u16,
     ) -> Result<Self, Box<dyn Error>> {
-        let gif_width = width * x_scale;
-        let gif_height = height * y_scale;
+        Self::with_format(file, Format::Gif, palette, width, height, x_scale, y_scale)
+    }
+
+    /// Creates a new animation in a given format with a given palette, size and scale
+    pub fn with_format(
+        path: &str,
+        format: Format,
+        palette: &[[u8; 3]],
+        width: u16,
+        height: u16,
+        x_scale: u16,
+        y_scale: u16,
+    ) -> Result<Self, Box<dyn Error>> {
+        let (gif_width, gif_height) = scaled_size(width, height, x_scale, y_scale, 0)?;
+        let sink = format.sink(path, palette, gif_width, gif_height)?;
+
+        Ok(Self::with_sink(sink, width, height, x_scale, y_scale))
+    }
+
+    /// Creates a new GIF with a caption bar below the scaled frames
+    pub fn with_caption(
+        file: &str,
+        palette: &[[u8; 3]],
+        width: u16,
+        height: u16,
+        x_scale: u16,
+        y_scale: u16,
+        caption: CaptionBar,
+    ) -> Result<Self, Box<dyn Error>> {
+        let (gif_width, gif_height) =
+            scaled_size(width, height, x_scale, y_scale, caption.height())?;
+        let sink = Format::Gif.sink(file, palette, gif_width, gif_height)?;
+
+        Ok(Self::with_sink_caption(
+            sink, width, height, x_scale, y_scale, caption,
+        ))
+    }
+}
+
+/// Returns the size of scaled frames with extra rows below, or an error if it doesn't
+/// fit in a u16
+pub fn scaled_size(
+    width: u16,
+    height: u16,
+    x_scale: u16,
+    y_scale: u16,
+    extra_height: u16,
+) -> Result<(u16, u16), Box<dyn Error>> {
+    let gif_width = width.checked_mul(x_scale);
+    let gif_height = height
+        .checked_mul(y_scale)
+        .and_then(|h| h.checked_add(extra_height));
 
-        // Create the flattened palette
-        let flat_pal = palette.iter().flatten().cloned().collect::<Vec<_>>();
+    match (gif_width, gif_height) {
+        (Some(w), Some(h)) => Ok((w, h)),
+        _ => Err(format!(
+            "Scaled size of {width}x{height} by {x_scale}x{y_scale} is too large"
+        ))?,
+    }
+}
 
-        // Create the encoder
-        let mut encoder = Encoder::new(File::create(file)?, gif_width, gif_height, &flat_pal)?;
+impl<W: Write> Gif<GifSink<W>> {
+    /// Creates a new GIF written to a writer, such as a `Vec<u8>` or stdout
+    pub fn from_writer(
+        writer: W,
+        palette: &[[u8; 3]],
+        width: u16,
+        height: u16,
+        x_scale: u16,
+        y_scale: u16,
+    ) -> Result<Self, Box<dyn Error>> {
+        let (gif_width, gif_height) = scaled_size(width, height, x_scale, y_scale, 0)?;
+        let sink = GifSink::new(writer, palette, gif_width, gif_height)?;
 
-        // Ininitely repeat
-        encoder.set_repeat(Repeat::Infinite)?;
+        Ok(Self::with_sink(sink, width, height, x_scale, y_scale))
+    }
 
-        Ok(Self {
+    /// Completes the GIF and returns the writer
+    pub fn into_writer(self) -> Result<W, Box<dyn Error>> {
+        self.sink.into_inner()
+    }
+}
+
+impl<S: FrameSink> Gif<S> {
+    /// Creates a new animation writing to a sink. The sink must have been created with
+    /// the scaled size
+    pub fn with_sink(sink: S, width: u16, height: u16, x_scale: u16, y_scale: u16) -> Self {
+        Self {
             width,
             height,
             x_scale,
             y_scale,
-            gif_width,
-            gif_height,
-            encoder,
+            gif_width: width * x_scale,
+            gif_height: height * y_scale,
+            sink,
             last_frame: None,
-        })
+            split_regions: false,
+            transparent_deltas: false,
+            caption: None,
+            caption_text: String::new(),
+            last_caption: None,
+            max_frames: None,
+            frames: 0,
+        }
+    }
+
+    /// Creates a new animation with a caption bar writing to a sink. The sink must have
+    /// been created with the scaled size plus the height of the caption bar
+    pub fn with_sink_caption(
+        sink: S,
+        width: u16,
+        height: u16,
+        x_scale: u16,
+        y_scale: u16,
+        caption: CaptionBar,
+    ) -> Self {
+        Self {
+            caption: Some(caption),
+            ..Self::with_sink(sink, width, height, x_scale, y_scale)
+        }
+    }
+
+    /// Sets whether changes in separate areas of a frame are written as separate sub-frames
+    /// with zero delay, when that is smaller than one sub-frame covering them all. Some
+    /// viewers show zero delay frames for a short time, so this is off by default
+    pub fn set_split_regions(&mut self, split: bool) {
+        self.split_regions = split;
     }
 
-    /// Output a frame to the GIF
+    /// Sets whether pixels left unchanged inside a changed region are written using the
+    /// sink's reserved transparent index, which usually compresses better. The index is
+    /// reserved when this is first turned on, so it should be set before drawing. Has no
+    /// effect if the palette is full
+    pub fn set_transparent_deltas(&mut self, deltas: bool) {
+        self.transparent_deltas = deltas;
+
+        if deltas {
+            self.sink.reserve_transparent();
+        }
+    }
+
+    /// Sets the maximum number of frames to output. Changed frames drawn after that are
+    /// ignored, but delays, including those for identical frames, are still added to the
+    /// last frame
+    pub fn set_max_frames(&mut self, max_frames: Option<usize>) {
+        self.max_frames = max_frames;
+    }
+
+    /// Sets the text shown in the caption bar from the next frame drawn
+    pub fn set_caption(&mut self, text: &str) {
+        self.caption_text.clear();
+        self.caption_text.push_str(text);
+    }
+
+    /// Output a frame to the GIF. Frames can be a canvas or a vector of rows
     pub fn draw_frame(
         &mut self,
-        frame_data: Vec<Vec<u8>>,
+        frame_data: impl Into<Canvas>,
         delay: u16,
     ) -> Result<(), Box<dyn Error>> {
         self.draw_frame_identical_check(frame_data, delay, IdenticalAction::Ignore)
@@ -63,68 +223,82 @@ impl Gif {
     /// Output a frame to the GIF and takes a given action if the frame is identical to the last
     pub fn draw_frame_identical_check(
         &mut self,
-        frame_data: Vec<Vec<u8>>,
+        frame_data: impl Into<Canvas>,
         delay: u16,
         identical_action: IdenticalAction,
     ) -> Result<(), Box<dyn Error>> {
-        // Make sure the frame looks like the correct size
-        assert_eq!(frame_data.len(), self.height as usize);
-        assert_eq!(frame_data[0].len(), self.width as usize);
-
-        // Calculate the difference between this frame and the last
-        match self.frame_difference(&frame_data) {
-            None => {
-                // No difference
-                match identical_action {
-                    IdenticalAction::Delay => self.delay(delay)?,
-                    IdenticalAction::Ignore => (),
-                }
+        let frame_data = frame_data.into();
+
+        // Make sure the frame is the correct size
+        assert_eq!(frame_data.height(), self.height as usize);
+        assert_eq!(frame_data.width(), self.width as usize);
+
+        // Calculate the differences between this frame and the last
+        let differences = self.frame_difference(&frame_data);
+
+        // Draw the caption bar if it has changed
+        let caption = self.caption_section();
+
+        if differences.is_empty() && caption.is_none() {
+            // No difference
+            match identical_action {
+                IdenticalAction::Delay => self.delay(delay)?,
+                IdenticalAction::Ignore => (),
             }
-            Some(difference) => {
-                // Scale the frame up
-                let out_section = frame_data
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(y, l)| {
-                        if difference.contains_y(y as u16) {
-                            Some(&l[difference.x_range()])
-                        } else {
-                            None
-                        }
-                    })
-                    .fold(
-                        Vec::with_capacity(self.gif_height as usize * self.gif_width as usize),
-                        |mut acc: Vec<u8>, line| {
-                            let expanded_line: Vec<u8> = line
-                                .iter()
-                                .flat_map(|pix| vec![*pix; self.x_scale as usize])
-                                .collect();
-
-                            for _ in 0..self.y_scale {
-                                acc.extend(&expanded_line);
-                            }
-
-                            acc
-                        },
-                    );
-
-                // Create the next frame
-                let frame = Frame {
+        } else if self.max_frames.is_some_and(|max| self.frames >= max) {
+            // Stop drawing at the maximum number of frames
+        } else {
+            // Mark unchanged pixels transparent?
+            let transparent = if self.transparent_deltas && self.last_frame.is_some() {
+                self.sink.transparent()
+            } else {
+                None
+            };
+
+            // Scale the frame up
+            let mut sections = differences
+                .iter()
+                .map(|difference| Section {
                     top: difference.top() * self.y_scale,
                     left: difference.left() * self.x_scale,
                     width: difference.width() * self.x_scale,
                     height: difference.height() * self.y_scale,
-                    buffer: Cow::Borrowed(&*out_section),
-                    delay: max(2, delay),
-                    ..Default::default()
-                };
+                    pixels: self.scale_region(&frame_data, difference, transparent),
+                    transparent,
+                })
+                .collect::<Vec<_>>();
 
-                // Write out the frame
-                self.encoder.write_frame(&frame)?;
+            if let Some(caption) = caption {
+                match sections.last_mut() {
+                    // The first frame must cover the whole image, so join the bar on to the
+                    // bottom of it
+                    Some(section) if self.last_frame.is_none() => {
+                        section.height += caption.height;
+                        section.pixels.extend(caption.pixels);
+                    }
+                    _ => sections.push(caption),
+                }
+            }
+
+            for (i, section) in sections.iter().enumerate() {
+                // Write out the frame. Only the last section is delayed
+                let continued = i < sections.len() - 1;
 
-                // Save the last frame
-                self.last_frame = Some(frame_data);
+                self.sink.write_frame(&SubFrame {
+                    top: section.top,
+                    left: section.left,
+                    width: section.width,
+                    height: section.height,
+                    pixels: &section.pixels,
+                    delay: if continued { 0 } else { delay },
+                    continued,
+                    transparent: section.transparent,
+                })?;
             }
+
+            // Save the last frame
+            self.last_frame = Some(frame_data);
+            self.frames += 1;
         }
 
         Ok(())
@@ -132,20 +306,12 @@ impl Gif {
 
     /// Creates an empty delay frame
     pub fn delay(&mut self, delay: u16) -> Result<(), Box<dyn Error>> {
-        // Create the next frame
-        let frame = Frame {
-            delay: max(2, delay),
-            width: 1,
-            height: 1,
-            transparent: Some(0),
-            buffer: Cow::Owned(vec![0]),
-            ..Default::default()
-        };
-
-        // Write out the frame
-        self.encoder.write_frame(&frame)?;
+        self.sink.delay(delay)
+    }
 
-        Ok(())
+    /// Completes the output, reporting any errors. Dropping the animation also completes it
+    pub fn finish(mut self) -> Result<(), Box<dyn Error>> {
+        self.sink.finish()
     }
 
     /// Returns dimensions of the pre-scaled image
@@ -165,15 +331,82 @@ impl Gif {
         vec![vec![0; w as usize]; h as usize]
     }
 
-    /// Calculates the difference between a given frame and the last frame output
-    /// Returns None if there is no difference
+    /// Returns a new empty canvas for the image
+    #[inline]
+    pub fn empty_canvas(&self) -> Canvas {
+        Canvas::new(self.width as usize, self.height as usize)
+    }
+
+    /// Returns the caption bar if the caption has changed since it was last drawn
+    fn caption_section(&mut self) -> Option<Section> {
+        let caption = self.caption?;
+
+        if self.last_caption.as_ref() == Some(&self.caption_text) {
+            return None;
+        }
+
+        self.last_caption = Some(self.caption_text.clone());
+
+        Some(Section {
+            left: 0,
+            top: self.gif_height,
+            width: self.gif_width,
+            height: caption.height(),
+            pixels: caption.draw(self.gif_width, &self.caption_text),
+            transparent: None,
+        })
+    }
+
+    /// Returns the scaled up pixels of a region of a frame. Pixels unchanged from the last
+    /// frame are replaced with the transparent index if given
+    fn scale_region(
+        &self,
+        frame_data: &Canvas,
+        region: &Region,
+        transparent: Option<u8>,
+    ) -> Vec<u8> {
+        let (x_scale, y_scale) = (self.x_scale as usize, self.y_scale as usize);
+        let row_len = region.width() as usize * x_scale;
+
+        let mut pixels = Vec::with_capacity(row_len * region.height() as usize * y_scale);
+
+        for y in region.y_range() {
+            let line = &frame_data.row(y)[region.x_range()];
+            let last_line = self
+                .last_frame
+                .as_ref()
+                .map(|f| &f.row(y)[region.x_range()]);
+
+            // Expand each pixel across
+            for (x, &pix) in line.iter().enumerate() {
+                let pix = match (transparent, last_line) {
+                    (Some(transparent), Some(last_line)) if last_line[x] == pix => transparent,
+                    _ => pix,
+                };
+
+                pixels.extend(std::iter::repeat_n(pix, x_scale));
+            }
+
+            // Repeat the expanded row down
+            let start = pixels.len() - row_len;
+
+            for _ in 1..y_scale {
+                pixels.extend_from_within(start..start + row_len);
+            }
+        }
+
+        pixels
+    }
+
+    /// Calculates the differences between a given frame and the last frame output
+    /// Returns an empty vector if there is no difference
     /// If there is no previous frame then the whole frame is considered changed
-    fn frame_difference(&self, frame_data: &[Vec<u8>]) -> Option<Region> {
+    fn frame_difference(&self, frame_data: &Canvas) -> Vec<Region> {
         if let Some(last_frame) = &self.last_frame {
-            let mut region = Region::max_init();
+            let mut regions = DirtyRegions::new(self.split_regions);
 
             // Process each row
-            for (y, (l1, l2)) in last_frame.iter().zip(frame_data.iter()).enumerate() {
+            for (y, (l1, l2)) in last_frame.rows().zip(frame_data.rows()).enumerate() {
                 // Process each pixel
                 for (x, (_, _)) in l1
                     .iter()
@@ -181,18 +414,17 @@ impl Gif {
                     .enumerate()
                     .filter(|(_, (p1, p2))| *p1 != *p2)
                 {
-                    region.max_add(x as u16, y as u16)
+                    regions.add(x as u16, y as u16)
                 }
             }
 
-            if region.max_valid() {
-                Some(region)
-            } else {
-                None
-            }
+            // Each extra sub-frame costs around 32 bytes of headers
+            let cell_pixels = self.x_scale as usize * self.y_scale as usize;
+
+            regions.into_regions(32usize.div_ceil(cell_pixels))
         } else {
             // No previous frame
-            Some(Region::new(0, 0, self.height - 1, self.width - 1))
+            vec![Region::new(0, 0, self.height - 1, self.width - 1)]
         }
     }
 }
@@ -202,3 +434,6 @@ pub enum IdenticalAction {
     Ignore,
     Delay,
 }
+
+#[cfg(test)]
+mod tests;
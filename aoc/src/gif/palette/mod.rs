@@ -0,0 +1,204 @@
+use std::{error::Error, fmt};
+
+use fxhash::FxHashMap;
+pub use hsl::HSL;
+
+/// Handle to a colour in a palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Colour(u8);
+
+impl Colour {
+    /// Returns the palette index of the colour
+    #[inline]
+    pub fn index(self) -> u8 {
+        self.0
+    }
+}
+
+impl From<Colour> for u8 {
+    #[inline]
+    fn from(colour: Colour) -> Self {
+        colour.0
+    }
+}
+
+/// Handle to a run of consecutive colours in a palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ramp {
+    start: u8,
+    len: u16,
+}
+
+impl Ramp {
+    /// Returns the number of colours in the ramp
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns true if the ramp has no colours
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a colour in the ramp. Steps past the end return the last colour
+    pub fn get(&self, step: usize) -> Colour {
+        assert!(!self.is_empty(), "Empty ramp");
+
+        Colour(self.start + step.min(self.len() - 1) as u8)
+    }
+
+    /// Returns the colour for a value in the range 0 to max inclusive, spread across the ramp
+    pub fn scale(&self, value: usize, max: usize) -> Colour {
+        match (value.min(max) * (self.len().saturating_sub(1))).checked_div(max) {
+            Some(step) => self.get(step),
+            None => self.get(0),
+        }
+    }
+
+    /// Returns an iterator over the colours in the ramp
+    pub fn iter(&self) -> impl Iterator<Item = Colour> {
+        let start = self.start as u16;
+
+        (start..start + self.len).map(|i| Colour(i as u8))
+    }
+}
+
+/// Error returned when adding more than 256 colours to a palette
+#[derive(Debug)]
+pub struct PaletteFull;
+
+impl fmt::Display for PaletteFull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Palette has more than 256 colours")
+    }
+}
+
+impl Error for PaletteFull {}
+
+/// Indexed colour palette of up to 256 colours, with optional names for colours
+#[derive(Debug, Clone, Default)]
+pub struct Palette {
+    colours: Vec<[u8; 3]>,
+    names: FxHashMap<String, Colour>,
+}
+
+impl Palette {
+    /// Creates an empty palette
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a colour
+    pub fn add(&mut self, rgb: [u8; 3]) -> Result<Colour, PaletteFull> {
+        let colour = self.reserve(1)?.get(0);
+
+        self.colours.push(rgb);
+
+        Ok(colour)
+    }
+
+    /// Adds a colour which can be looked up by name
+    pub fn add_named(&mut self, name: &str, rgb: [u8; 3]) -> Result<Colour, PaletteFull> {
+        let colour = self.add(rgb)?;
+
+        self.names.insert(name.to_string(), colour);
+
+        Ok(colour)
+    }
+
+    /// Returns a named colour
+    pub fn get(&self, name: &str) -> Option<Colour> {
+        self.names.get(name).copied()
+    }
+
+    /// Adds a ramp of colours linearly interpolated between two RGB colours inclusive
+    pub fn ramp(&mut self, from: [u8; 3], to: [u8; 3], steps: usize) -> Result<Ramp, PaletteFull> {
+        let ramp = self.reserve(steps)?;
+
+        for step in 0..steps {
+            let t = fraction(step, steps);
+
+            self.colours
+                .push([0, 1, 2].map(|i| lerp(from[i] as f64, to[i] as f64, t).round() as u8));
+        }
+
+        Ok(ramp)
+    }
+
+    /// Adds a ramp of colours interpolated between two HSL colours inclusive
+    pub fn hsl_ramp(&mut self, from: HSL, to: HSL, steps: usize) -> Result<Ramp, PaletteFull> {
+        let ramp = self.reserve(steps)?;
+
+        for step in 0..steps {
+            let t = fraction(step, steps);
+
+            let hsl = HSL {
+                h: lerp(from.h, to.h, t).rem_euclid(360.0),
+                s: lerp(from.s, to.s, t),
+                l: lerp(from.l, to.l, t),
+            };
+
+            let (r, g, b) = hsl.to_rgb();
+
+            self.colours.push([r, g, b]);
+        }
+
+        Ok(ramp)
+    }
+
+    /// Adds a number of colours with hues spaced evenly around the colour wheel
+    pub fn hues(&mut self, count: usize, s: f64, l: f64) -> Result<Ramp, PaletteFull> {
+        let last = 360.0 * count.saturating_sub(1) as f64 / count.max(1) as f64;
+
+        self.hsl_ramp(HSL { h: 0.0, s, l }, HSL { h: last, s, l }, count)
+    }
+
+    /// Returns the RGB value of a colour
+    pub fn rgb(&self, colour: Colour) -> [u8; 3] {
+        self.colours[colour.0 as usize]
+    }
+
+    /// Returns the RGB colours in palette order
+    pub fn colours(&self) -> &[[u8; 3]] {
+        &self.colours
+    }
+
+    /// Returns the number of colours in the palette
+    pub fn len(&self) -> usize {
+        self.colours.len()
+    }
+
+    /// Returns true if the palette has no colours
+    pub fn is_empty(&self) -> bool {
+        self.colours.is_empty()
+    }
+
+    /// Checks there is room for a number of colours, returning the ramp they will occupy
+    fn reserve(&self, count: usize) -> Result<Ramp, PaletteFull> {
+        if self.colours.len() + count > 256 {
+            return Err(PaletteFull);
+        }
+
+        Ok(Ramp {
+            start: self.colours.len() as u8,
+            len: count as u16,
+        })
+    }
+}
+
+/// Returns how far a step is through a number of steps, from 0 to 1 inclusive
+fn fraction(step: usize, steps: usize) -> f64 {
+    if steps > 1 {
+        step as f64 / (steps - 1) as f64
+    } else {
+        0.0
+    }
+}
+
+/// Linearly interpolates between two values
+fn lerp(from: f64, to: f64, t: f64) -> f64 {
+    from + (to - from) * t
+}
+
+#[cfg(test)]
+mod tests;
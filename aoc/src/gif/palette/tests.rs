@@ -0,0 +1,90 @@
+use super::*;
+
+#[test]
+fn test_named() {
+    let mut palette = Palette::new();
+
+    let black = palette.add_named("black", [0, 0, 0]).unwrap();
+    let red = palette.add_named("red", [255, 0, 0]).unwrap();
+    let other = palette.add([1, 2, 3]).unwrap();
+
+    assert_eq!(black.index(), 0);
+    assert_eq!(u8::from(red), 1);
+    assert_eq!(other.index(), 2);
+
+    assert_eq!(palette.get("red"), Some(red));
+    assert_eq!(palette.get("green"), None);
+    assert_eq!(palette.rgb(red), [255, 0, 0]);
+    assert_eq!(palette.colours(), &[[0, 0, 0], [255, 0, 0], [1, 2, 3]]);
+}
+
+#[test]
+fn test_ramp() {
+    let mut palette = Palette::new();
+    palette.add([0, 0, 0]).unwrap();
+
+    let ramp = palette.ramp([0, 100, 200], [100, 100, 0], 5).unwrap();
+    assert_eq!(ramp.len(), 5);
+    assert_eq!(
+        ramp.iter().map(|c| palette.rgb(c)).collect::<Vec<_>>(),
+        vec![
+            [0, 100, 200],
+            [25, 100, 150],
+            [50, 100, 100],
+            [75, 100, 50],
+            [100, 100, 0]
+        ]
+    );
+
+    assert_eq!(ramp.get(0).index(), 1);
+    assert_eq!(ramp.get(10).index(), 5);
+    assert_eq!(ramp.scale(0, 8), ramp.get(0));
+    assert_eq!(ramp.scale(4, 8), ramp.get(2));
+    assert_eq!(ramp.scale(8, 8), ramp.get(4));
+}
+
+#[test]
+fn test_hsl() {
+    let mut palette = Palette::new();
+
+    // Red, green and blue
+    let hues = palette.hues(3, 1.0, 0.5).unwrap();
+    assert_eq!(
+        hues.iter().map(|c| palette.rgb(c)).collect::<Vec<_>>(),
+        vec![[255, 0, 0], [0, 255, 0], [0, 0, 255]]
+    );
+
+    // Black to white
+    let greys = palette
+        .hsl_ramp(
+            HSL {
+                h: 0.0,
+                s: 0.0,
+                l: 0.0,
+            },
+            HSL {
+                h: 0.0,
+                s: 0.0,
+                l: 1.0,
+            },
+            2,
+        )
+        .unwrap();
+    assert_eq!(palette.rgb(greys.get(0)), [0, 0, 0]);
+    assert_eq!(palette.rgb(greys.get(1)), [255, 255, 255]);
+}
+
+#[test]
+fn test_full() {
+    let mut palette = Palette::new();
+
+    palette.ramp([0, 0, 0], [255, 255, 255], 255).unwrap();
+    assert!(palette.ramp([0, 0, 0], [0, 0, 0], 2).is_err());
+
+    let last = palette.add([1, 1, 1]).unwrap();
+    assert_eq!(last.index(), 255);
+    assert_eq!(palette.len(), 256);
+    assert!(palette.add([1, 1, 1]).is_err());
+    assert!(palette.add_named("extra", [1, 1, 1]).is_err());
+    assert_eq!(palette.get("extra"), None);
+}
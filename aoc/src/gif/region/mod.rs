@@ -0,0 +1,195 @@
+use std::{
+    cmp::{max, min},
+    ops::RangeInclusive,
+};
+
+/// Structure describing a rectanglar region
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Region {
+    top: u16,
+    left: u16,
+    bottom: u16,
+    right: u16,
+}
+
+impl Region {
+    /// Creates a new region
+    pub fn new(top: u16, left: u16, bottom: u16, right: u16) -> Self {
+        Region {
+            top,
+            left,
+            bottom,
+            right,
+        }
+    }
+
+    /// Returns the left position of the region
+    pub fn left(&self) -> u16 {
+        self.left
+    }
+
+    /// Returns the top position of the region
+    pub fn top(&self) -> u16 {
+        self.top
+    }
+
+    /// Returns the width of the region
+    pub fn width(&self) -> u16 {
+        (self.right - self.left) + 1
+    }
+
+    /// Returns the height of the region
+    pub fn height(&self) -> u16 {
+        (self.bottom - self.top) + 1
+    }
+
+    /// Returns a range for all x coordinates
+    pub fn x_range(&self) -> RangeInclusive<usize> {
+        (self.left as usize)..=(self.right as usize)
+    }
+
+    /// Returns a range for all y coordinates
+    pub fn y_range(&self) -> RangeInclusive<usize> {
+        (self.top as usize)..=(self.bottom as usize)
+    }
+
+    /// Initialises a region for the max region calculation
+    pub fn max_init() -> Self {
+        Self {
+            top: u16::MAX,
+            left: u16::MAX,
+            bottom: 0,
+            right: 0,
+        }
+    }
+
+    /// Adds a coordinate in the max region calculation
+    pub fn max_add(&mut self, x: u16, y: u16) {
+        self.top = min(self.top, y);
+        self.left = min(self.left, x);
+        self.bottom = max(self.bottom, y);
+        self.right = max(self.right, x);
+    }
+
+    /// Returns true if max has been calculated successfully
+    pub fn max_valid(&self) -> bool {
+        self.top != u16::MAX
+    }
+
+    /// Returns the number of cells in the region
+    pub fn area(&self) -> usize {
+        self.width() as usize * self.height() as usize
+    }
+
+    /// Returns the smallest region containing both regions
+    pub fn union(&self, other: &Region) -> Region {
+        Region {
+            top: min(self.top, other.top),
+            left: min(self.left, other.left),
+            bottom: max(self.bottom, other.bottom),
+            right: max(self.right, other.right),
+        }
+    }
+
+    /// Returns true if the regions share any cells
+    pub fn intersects(&self, other: &Region) -> bool {
+        self.left <= other.right
+            && other.left <= self.right
+            && self.top <= other.bottom
+            && other.top <= self.bottom
+    }
+
+    /// Returns true if a coordinate is in or next to the region
+    fn touches(&self, x: u16, y: u16) -> bool {
+        x + 1 >= self.left && x <= self.right + 1 && y + 1 >= self.top && y <= self.bottom + 1
+    }
+}
+
+/// Maximum number of separate regions tracked before falling back to a single region
+const MAX_REGIONS: usize = 16;
+
+/// Collects changed coordinates in to a set of disjoint regions
+pub struct DirtyRegions {
+    split: bool,
+    bounds: Region,
+    regions: Vec<Region>,
+}
+
+impl DirtyRegions {
+    /// Creates an empty set of regions. If not splitting, only the bounding region is collected
+    pub fn new(split: bool) -> Self {
+        Self {
+            split,
+            bounds: Region::max_init(),
+            regions: Vec::new(),
+        }
+    }
+
+    /// Adds a changed coordinate
+    pub fn add(&mut self, x: u16, y: u16) {
+        self.bounds.max_add(x, y);
+
+        if !self.split {
+            return;
+        }
+
+        // Extend a region next to the coordinate or start a new one
+        let full = self.regions.len() >= MAX_REGIONS;
+
+        match self.regions.iter_mut().find(|r| r.touches(x, y)) {
+            Some(region) => region.max_add(x, y),
+            None if full => {
+                // Too many regions - fall back to the bounding region
+                self.split = false;
+                self.regions.clear();
+            }
+            None => self.regions.push(Region::new(y, x, y, x)),
+        }
+    }
+
+    /// Returns the changed regions. Regions are merged where encoding them together costs
+    /// no more than separately, where each extra region costs `region_cost` cells. A single
+    /// bounding region is returned if splitting saves nothing. Returns an empty vector if
+    /// nothing has changed
+    pub fn into_regions(mut self, region_cost: usize) -> Vec<Region> {
+        if !self.bounds.max_valid() {
+            return Vec::new();
+        }
+
+        if !self.split {
+            return vec![self.bounds];
+        }
+
+        // Merge pairs of regions until no more are worth merging
+        while let Some((i, j)) = self.merge_candidate(region_cost) {
+            let other = self.regions.swap_remove(j);
+            self.regions[i] = self.regions[i].union(&other);
+        }
+
+        // Check splitting is worth it
+        let split_cost = self.regions.iter().map(Region::area).sum::<usize>()
+            + (self.regions.len() - 1) * region_cost;
+
+        if split_cost < self.bounds.area() {
+            self.regions
+        } else {
+            vec![self.bounds]
+        }
+    }
+
+    /// Finds a pair of regions which overlap or are cheaper to encode as one
+    fn merge_candidate(&self, region_cost: usize) -> Option<(usize, usize)> {
+        for (i, r1) in self.regions.iter().enumerate() {
+            for (j, r2) in self.regions.iter().enumerate().skip(i + 1) {
+                if r1.intersects(r2) || r1.union(r2).area() <= r1.area() + r2.area() + region_cost {
+                    return Some((i, j));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,102 @@
+use super::*;
+
+fn dirty(points: &[(u16, u16)]) -> DirtyRegions {
+    let mut regions = DirtyRegions::new(true);
+
+    for &(x, y) in points {
+        regions.add(x, y);
+    }
+
+    regions
+}
+
+#[test]
+fn test_region() {
+    let r1 = Region::new(1, 1, 2, 3);
+    let r2 = Region::new(2, 3, 4, 4);
+    let r3 = Region::new(3, 0, 4, 1);
+
+    assert_eq!(r1.area(), 6);
+    assert_eq!(r1.union(&r2), Region::new(1, 1, 4, 4));
+    assert!(r1.intersects(&r2));
+    assert!(!r1.intersects(&r3));
+    assert!(!r2.intersects(&r3));
+}
+
+#[test]
+fn test_no_change() {
+    assert_eq!(dirty(&[]).into_regions(0), vec![]);
+}
+
+#[test]
+fn test_far_apart() {
+    // Two far apart changes are split
+    let regions = dirty(&[(0, 0), (1, 0), (50, 40), (50, 41)]).into_regions(4);
+    assert_eq!(
+        regions,
+        vec![Region::new(0, 0, 0, 1), Region::new(40, 50, 41, 50)]
+    );
+
+    // Unless not splitting
+    let mut regions = DirtyRegions::new(false);
+    regions.add(0, 0);
+    regions.add(50, 40);
+    assert_eq!(regions.into_regions(4), vec![Region::new(0, 0, 40, 50)]);
+}
+
+#[test]
+fn test_close_together() {
+    // Nearby changes cost less as one region
+    let regions = dirty(&[(0, 0), (2, 0)]).into_regions(4);
+    assert_eq!(regions, vec![Region::new(0, 0, 0, 2)]);
+
+    // Adjacent changes build one region
+    let regions = dirty(&[(5, 5), (6, 5), (4, 6), (5, 6), (6, 7)]).into_regions(0);
+    assert_eq!(regions, vec![Region::new(5, 4, 7, 6)]);
+}
+
+#[test]
+fn test_overlapping_merged() {
+    // The arms of a U start as separate regions and overlap once the bottom is added
+    let regions = dirty(&[
+        (0, 0),
+        (4, 0),
+        (0, 1),
+        (4, 1),
+        (0, 2),
+        (1, 2),
+        (2, 2),
+        (3, 2),
+        (4, 2),
+    ])
+    .into_regions(0);
+    assert_eq!(regions, vec![Region::new(0, 0, 2, 4)]);
+}
+
+#[test]
+fn test_too_many() {
+    // Falls back to the bounding region
+    let points = (0..20).map(|i| (i * 10, i * 10)).collect::<Vec<_>>();
+    assert_eq!(
+        dirty(&points).into_regions(0),
+        vec![Region::new(0, 0, 190, 190)]
+    );
+}
+
+#[test]
+fn test_region_cap() {
+    // Exactly the maximum number of regions are kept
+    let points = (0..MAX_REGIONS as u16)
+        .map(|i| (i * 10, i * 10))
+        .collect::<Vec<_>>();
+    assert_eq!(dirty(&points).into_regions(0).len(), MAX_REGIONS);
+
+    // One more falls back to the bounding region
+    let points = (0..=MAX_REGIONS as u16)
+        .map(|i| (i * 10, i * 10))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        dirty(&points).into_regions(0),
+        vec![Region::new(0, 0, 160, 160)]
+    );
+}
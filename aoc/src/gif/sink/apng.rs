@@ -0,0 +1,135 @@
+use std::{error::Error, io::Write};
+
+use png::{BitDepth, BlendOp, ColorType, DisposeOp, Encoder};
+
+use super::{flat_palette, transparent_index, FrameSink, SubFrame};
+
+/// Writes frames as an infinitely repeating lossless animated PNG. The frame count is
+/// needed up front, so frames are held in memory until the sink is finished. An extra
+/// palette entry is reserved for transparency if asked for before the first frame and
+/// there is room
+pub struct ApngSink<W: Write> {
+    writer: W,
+    palette: Vec<[u8; 3]>,
+    transparent: Option<u8>,
+    width: u16,
+    height: u16,
+    frames: Vec<Stored>,
+    finished: bool,
+}
+
+/// A frame waiting to be written
+struct Stored {
+    left: u16,
+    top: u16,
+    width: u16,
+    height: u16,
+    pixels: Vec<u8>,
+    delay: u16,
+    blend: bool,
+}
+
+impl<W: Write> ApngSink<W> {
+    /// Creates an APNG sink with a given palette and size
+    pub fn new(writer: W, palette: &[[u8; 3]], width: u16, height: u16) -> Self {
+        Self {
+            writer,
+            palette: palette.to_vec(),
+            transparent: None,
+            width,
+            height,
+            frames: Vec::new(),
+            finished: false,
+        }
+    }
+}
+
+impl<W: Write> FrameSink for ApngSink<W> {
+    fn write_frame(&mut self, frame: &SubFrame) -> Result<(), Box<dyn Error>> {
+        // The first frame is the default image so must cover it all
+        if self.frames.is_empty() && (frame.width, frame.height) != (self.width, self.height) {
+            Err("First APNG frame must cover the whole image")?
+        }
+
+        self.frames.push(Stored {
+            left: frame.left,
+            top: frame.top,
+            width: frame.width,
+            height: frame.height,
+            pixels: frame.pixels.to_vec(),
+            delay: frame.delay,
+            blend: frame.transparent.is_some(),
+        });
+
+        Ok(())
+    }
+
+    fn delay(&mut self, delay: u16) -> Result<(), Box<dyn Error>> {
+        if let Some(last) = self.frames.last_mut() {
+            last.delay = last.delay.saturating_add(delay);
+        }
+
+        Ok(())
+    }
+
+    fn transparent(&self) -> Option<u8> {
+        self.transparent
+    }
+
+    fn reserve_transparent(&mut self) {
+        if self.frames.is_empty() {
+            self.transparent = transparent_index(&self.palette);
+        }
+    }
+
+    fn finish(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.finished || self.frames.is_empty() {
+            return Ok(());
+        }
+
+        self.finished = true;
+
+        // Create the encoder
+        let mut encoder = Encoder::new(&mut self.writer, self.width as u32, self.height as u32);
+        encoder.set_color(ColorType::Indexed);
+        encoder.set_depth(BitDepth::Eight);
+        encoder.set_palette(flat_palette(&self.palette, self.transparent));
+
+        if let Some(transparent) = self.transparent {
+            let mut trns = vec![255; transparent as usize + 1];
+            trns[transparent as usize] = 0;
+            encoder.set_trns(trns);
+        }
+
+        encoder.set_animated(self.frames.len() as u32, 0)?;
+
+        let mut writer = encoder.write_header()?;
+
+        for frame in &self.frames {
+            // Move to the origin first so the new size is always in bounds
+            writer.set_frame_position(0, 0)?;
+            writer.set_frame_dimension(frame.width as u32, frame.height as u32)?;
+            writer.set_frame_position(frame.left as u32, frame.top as u32)?;
+            writer.set_frame_delay(frame.delay, 100)?;
+            writer.set_dispose_op(DisposeOp::None)?;
+            writer.set_blend_op(if frame.blend {
+                BlendOp::Over
+            } else {
+                BlendOp::Source
+            })?;
+
+            writer.write_image_data(&frame.pixels)?;
+        }
+
+        writer.finish()?;
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Drop for ApngSink<W> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
@@ -0,0 +1,114 @@
+use std::{borrow::Cow, cmp::max, error::Error, io::Write};
+
+use ::gif::{DisposalMethod, Encoder, Frame, Repeat};
+
+use super::{flat_palette, transparent_index, FrameSink, SubFrame};
+
+/// Writes frames as an infinitely repeating animated GIF. An extra palette entry is reserved
+/// for transparency if asked for before the first frame and there is room
+pub struct GifSink<W: Write> {
+    /// Writer and size waiting for the first frame, when the colour table is written
+    pending: Option<(W, u16, u16)>,
+    encoder: Option<Encoder<W>>,
+    palette: Vec<[u8; 3]>,
+    transparent: Option<u8>,
+}
+
+impl<W: Write> GifSink<W> {
+    /// Creates a GIF sink with a given palette and size
+    pub fn new(
+        writer: W,
+        palette: &[[u8; 3]],
+        width: u16,
+        height: u16,
+    ) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            pending: Some((writer, width, height)),
+            encoder: None,
+            palette: palette.to_vec(),
+            transparent: None,
+        })
+    }
+
+    /// Writes the GIF trailer and returns the writer
+    pub fn into_inner(mut self) -> Result<W, Box<dyn Error>> {
+        self.encoder()?;
+
+        let encoder = self.encoder.take().ok_or("GIF encoder not created")?;
+
+        Ok(encoder.into_inner()?)
+    }
+
+    /// Returns the encoder, creating it with the final palette on first use
+    fn encoder(&mut self) -> Result<&mut Encoder<W>, Box<dyn Error>> {
+        if let Some((writer, width, height)) = self.pending.take() {
+            // Create the flattened palette
+            let flat_pal = flat_palette(&self.palette, self.transparent);
+
+            // Create the encoder
+            let mut encoder = Encoder::new(writer, width, height, &flat_pal)?;
+
+            // Ininitely repeat
+            encoder.set_repeat(Repeat::Infinite)?;
+
+            self.encoder = Some(encoder);
+        }
+
+        Ok(self.encoder.as_mut().ok_or("GIF encoder not created")?)
+    }
+}
+
+impl<W: Write> FrameSink for GifSink<W> {
+    fn write_frame(&mut self, frame: &SubFrame) -> Result<(), Box<dyn Error>> {
+        // Create the next frame
+        let frame = Frame {
+            top: frame.top,
+            left: frame.left,
+            width: frame.width,
+            height: frame.height,
+            buffer: Cow::Borrowed(frame.pixels),
+            transparent: frame.transparent,
+            dispose: DisposalMethod::Keep,
+            delay: if frame.continued {
+                0
+            } else {
+                max(2, frame.delay)
+            },
+            ..Default::default()
+        };
+
+        // Write out the frame
+        self.encoder()?.write_frame(&frame)?;
+
+        Ok(())
+    }
+
+    fn delay(&mut self, delay: u16) -> Result<(), Box<dyn Error>> {
+        // Create the next frame with a single transparent pixel
+        let transparent = self.transparent.unwrap_or(0);
+
+        let frame = Frame {
+            delay: max(2, delay),
+            width: 1,
+            height: 1,
+            transparent: Some(transparent),
+            buffer: Cow::Owned(vec![transparent]),
+            ..Default::default()
+        };
+
+        // Write out the frame
+        self.encoder()?.write_frame(&frame)?;
+
+        Ok(())
+    }
+
+    fn transparent(&self) -> Option<u8> {
+        self.transparent
+    }
+
+    fn reserve_transparent(&mut self) {
+        if self.pending.is_some() {
+            self.transparent = transparent_index(&self.palette);
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    error::Error,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    str::FromStr,
+};
+
+use super::PaletteFull;
+
+pub use self::{apng::ApngSink, gif::GifSink, png_dir::PngDirSink, term::TermSink, y4m::Y4mSink};
+
+mod apng;
+mod gif;
+mod png_dir;
+mod term;
+mod y4m;
+
+/// Destination for the scaled, palette indexed frames of an animation
+pub trait FrameSink {
+    /// Writes a frame updating an area of the image
+    fn write_frame(&mut self, frame: &SubFrame) -> Result<(), Box<dyn Error>>;
+
+    /// Extends the time the last frame is shown for by a number of hundredths of a second
+    fn delay(&mut self, delay: u16) -> Result<(), Box<dyn Error>>;
+
+    /// Returns the palette index reserved for pixels left unchanged by a frame, if there is one
+    fn transparent(&self) -> Option<u8> {
+        None
+    }
+
+    /// Asks for a palette index to be reserved for unchanged pixels if there is room. Sinks
+    /// writing a colour table only reserve one when asked before the first frame
+    fn reserve_transparent(&mut self) {}
+
+    /// Completes the output. Sinks holding frames back also finish when dropped, ignoring errors
+    fn finish(&mut self) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+}
+
+impl<S: FrameSink + ?Sized> FrameSink for Box<S> {
+    fn write_frame(&mut self, frame: &SubFrame) -> Result<(), Box<dyn Error>> {
+        (**self).write_frame(frame)
+    }
+
+    fn delay(&mut self, delay: u16) -> Result<(), Box<dyn Error>> {
+        (**self).delay(delay)
+    }
+
+    fn transparent(&self) -> Option<u8> {
+        (**self).transparent()
+    }
+
+    fn reserve_transparent(&mut self) {
+        (**self).reserve_transparent()
+    }
+
+    fn finish(&mut self) -> Result<(), Box<dyn Error>> {
+        (**self).finish()
+    }
+}
+
+/// Scaled pixels for an area of the image and the number of hundredths of a second to show them for.
+/// A continued sub-frame is shown together with the next one. Pixels set to the transparent
+/// index are left unchanged
+pub struct SubFrame<'a> {
+    pub left: u16,
+    pub top: u16,
+    pub width: u16,
+    pub height: u16,
+    pub pixels: &'a [u8],
+    pub delay: u16,
+    pub continued: bool,
+    pub transparent: Option<u8>,
+}
+
+/// Output file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Animated GIF
+    Gif,
+    /// Lossless animated PNG
+    Apng,
+    /// Directory of numbered PNG frames with an ffmpeg concat file holding the frame durations
+    PngDir,
+    /// Uncompressed YUV4MPEG2 video stream
+    Y4m,
+    /// Live view in the terminal. The path is ignored
+    Term,
+}
+
+impl Format {
+    /// Creates a sink writing this format to a path. A path of "-" writes streamable formats to stdout
+    pub fn sink(
+        &self,
+        path: &str,
+        palette: &[[u8; 3]],
+        width: u16,
+        height: u16,
+    ) -> Result<Box<dyn FrameSink>, Box<dyn Error>> {
+        if palette.len() > 256 {
+            Err(PaletteFull)?
+        }
+
+        Ok(match self {
+            Format::Gif => Box::new(GifSink::new(writer(path)?, palette, width, height)?),
+            Format::Apng => Box::new(ApngSink::new(writer(path)?, palette, width, height)),
+            Format::PngDir => Box::new(PngDirSink::new(path, palette, width, height)?),
+            Format::Y4m => Box::new(Y4mSink::new(writer(path)?, palette, width, height)?),
+            Format::Term => Box::new(TermSink::stdout(palette, width, height)?),
+        })
+    }
+
+    /// Guesses the format of a path from its extension. Paths ending in a slash are PNG directories
+    pub fn from_path(path: &str) -> Option<Self> {
+        if path.ends_with('/') {
+            return Some(Format::PngDir);
+        }
+
+        match Path::new(path).extension()?.to_str()? {
+            "gif" => Some(Format::Gif),
+            "png" | "apng" => Some(Format::Apng),
+            "y4m" => Some(Format::Y4m),
+            _ => None,
+        }
+    }
+
+    /// Changes the extension of a path to suit this format
+    pub fn path_for(&self, path: &str) -> String {
+        let path = Path::new(path);
+
+        let path = match self {
+            Format::Gif => path.with_extension("gif"),
+            Format::Apng => path.with_extension("png"),
+            Format::PngDir => path.with_extension(""),
+            Format::Y4m => path.with_extension("y4m"),
+            Format::Term => return "-".to_string(),
+        };
+
+        path.to_string_lossy().into_owned()
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "gif" => Ok(Format::Gif),
+            "apng" => Ok(Format::Apng),
+            "png-dir" => Ok(Format::PngDir),
+            "y4m" => Ok(Format::Y4m),
+            "term" => Ok(Format::Term),
+            _ => Err(format!("Unknown format '{s}'")),
+        }
+    }
+}
+
+/// Opens a buffered writer for a path, or stdout if the path is "-"
+fn writer(path: &str) -> Result<Box<dyn Write>, Box<dyn Error>> {
+    if path == "-" {
+        Ok(Box::new(BufWriter::new(io::stdout())))
+    } else {
+        Ok(Box::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+/// Returns the index after the last palette entry to reserve for transparency, if there is room
+fn transparent_index(palette: &[[u8; 3]]) -> Option<u8> {
+    (palette.len() < 256).then_some(palette.len() as u8)
+}
+
+/// Flattens a palette, adding an entry for the transparent index if one is reserved
+fn flat_palette(palette: &[[u8; 3]], transparent: Option<u8>) -> Vec<u8> {
+    let mut flat = palette.iter().flatten().cloned().collect::<Vec<_>>();
+
+    if transparent.is_some() {
+        flat.extend([0, 0, 0]);
+    }
+
+    flat
+}
+
+/// Full size image built up from sub-frames, for formats which only store whole frames
+struct Screen {
+    width: u16,
+    pixels: Vec<u8>,
+}
+
+impl Screen {
+    /// Creates a screen filled with palette index 0
+    fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            pixels: vec![0; width as usize * height as usize],
+        }
+    }
+
+    /// Copies a sub-frame on to the screen
+    fn apply(&mut self, frame: &SubFrame) {
+        let width = self.width as usize;
+
+        for (y, row) in frame.pixels.chunks_exact(frame.width as usize).enumerate() {
+            let start = (frame.top as usize + y) * width + frame.left as usize;
+            let dest = &mut self.pixels[start..start + row.len()];
+
+            match frame.transparent {
+                None => dest.copy_from_slice(row),
+                Some(transparent) => {
+                    for (d, &p) in dest.iter_mut().zip(row) {
+                        if p != transparent {
+                            *d = p;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
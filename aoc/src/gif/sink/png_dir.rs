@@ -0,0 +1,117 @@
+use std::{
+    error::Error,
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::PathBuf,
+};
+
+use png::{BitDepth, ColorType, Encoder};
+
+use super::{transparent_index, FrameSink, Screen, SubFrame};
+
+/// Writes each frame as a numbered PNG file in a directory. The frame durations are
+/// written to frames.txt in ffmpeg concat demuxer format when the sink is finished
+pub struct PngDirSink {
+    dir: PathBuf,
+    palette: Vec<u8>,
+    transparent: Option<u8>,
+    width: u16,
+    height: u16,
+    screen: Screen,
+    delays: Vec<u16>,
+    finished: bool,
+}
+
+impl PngDirSink {
+    /// Creates a PNG directory sink with a given palette and size. The directory is created if needed
+    pub fn new(
+        dir: &str,
+        palette: &[[u8; 3]],
+        width: u16,
+        height: u16,
+    ) -> Result<Self, Box<dyn Error>> {
+        fs::create_dir_all(dir)?;
+
+        Ok(Self {
+            dir: PathBuf::from(dir),
+            palette: palette.iter().flatten().cloned().collect(),
+            transparent: transparent_index(palette),
+            width,
+            height,
+            screen: Screen::new(width, height),
+            delays: Vec::new(),
+            finished: false,
+        })
+    }
+
+    /// Returns the file name of a frame
+    fn frame_name(frame: usize) -> String {
+        format!("frame{frame:06}.png")
+    }
+}
+
+impl FrameSink for PngDirSink {
+    fn write_frame(&mut self, frame: &SubFrame) -> Result<(), Box<dyn Error>> {
+        self.screen.apply(frame);
+
+        // Wait for the rest of the frame
+        if frame.continued {
+            return Ok(());
+        }
+
+        // Write the whole screen to the next numbered file
+        let file = File::create(self.dir.join(Self::frame_name(self.delays.len())))?;
+
+        let mut encoder = Encoder::new(BufWriter::new(file), self.width as u32, self.height as u32);
+        encoder.set_color(ColorType::Indexed);
+        encoder.set_depth(BitDepth::Eight);
+        encoder.set_palette(&self.palette);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.screen.pixels)?;
+        writer.finish()?;
+
+        self.delays.push(frame.delay);
+
+        Ok(())
+    }
+
+    fn delay(&mut self, delay: u16) -> Result<(), Box<dyn Error>> {
+        if let Some(last) = self.delays.last_mut() {
+            *last = last.saturating_add(delay);
+        }
+
+        Ok(())
+    }
+
+    fn transparent(&self) -> Option<u8> {
+        self.transparent
+    }
+
+    fn finish(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.finished {
+            return Ok(());
+        }
+
+        self.finished = true;
+
+        let mut concat = BufWriter::new(File::create(self.dir.join("frames.txt"))?);
+
+        writeln!(concat, "ffconcat version 1.0")?;
+
+        for (frame, delay) in self.delays.iter().enumerate() {
+            writeln!(concat, "file {}", Self::frame_name(frame))?;
+            writeln!(concat, "duration {}.{:02}", delay / 100, delay % 100)?;
+        }
+
+        concat.flush()?;
+
+        Ok(())
+    }
+}
+
+impl Drop for PngDirSink {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
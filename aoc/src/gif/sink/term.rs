@@ -0,0 +1,328 @@
+use std::{
+    error::Error,
+    io::{self, IsTerminal, Stdout, Write},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    queue,
+    style::{Color, Print, ResetColor, SetBackgroundColor, SetForegroundColor},
+    terminal::{self, Clear, ClearType},
+};
+
+use super::{transparent_index, FrameSink, Screen, SubFrame};
+
+/// Key help shown below the image when keyboard controls are enabled
+const HELP: &str = "[space] pause  [s] step  [+/-] speed  [q] quit";
+
+/// Shows frames in a terminal using ANSI truecolour, drawing two pixels in each character
+/// cell with the upper half block character. Large images are shrunk by sampling every
+/// nth pixel. Delays are waited out in real time, and keyboard controls for pausing,
+/// stepping and changing speed are available if enabled
+pub struct TermSink<W: Write> {
+    writer: W,
+    palette: Vec<[u8; 3]>,
+    transparent: Option<u8>,
+    width: u16,
+    height: u16,
+    step: u16,
+    screen: Screen,
+    shown: Vec<Option<Cell>>,
+    cursor: Option<(u16, u16)>,
+    colours: (Option<Color>, Option<Color>),
+    controls: bool,
+    paused: bool,
+    speed: f64,
+    finished: bool,
+}
+
+/// Top and bottom pixels of a character cell. The bottom pixel is missing on the last row
+/// of an image with an odd height
+type Cell = (u8, Option<u8>);
+
+impl TermSink<Stdout> {
+    /// Creates a terminal sink on stdout shrinking the image to fit the terminal. Keyboard
+    /// controls are enabled if stdin and stdout are terminals
+    pub fn stdout(palette: &[[u8; 3]], width: u16, height: u16) -> Result<Self, Box<dyn Error>> {
+        let (cols, rows) = terminal::size().unwrap_or((80, 24));
+
+        // Leave a row for the status line
+        let step = width
+            .div_ceil(cols.max(1))
+            .max(height.div_ceil(rows.saturating_sub(1).max(1) * 2));
+
+        let sink = Self::new(io::stdout(), palette, width, height, step);
+
+        if io::stdin().is_terminal() && io::stdout().is_terminal() {
+            sink.with_controls()
+        } else {
+            Ok(sink)
+        }
+    }
+}
+
+impl<W: Write> TermSink<W> {
+    /// Creates a terminal sink with a given palette and size, drawing every step'th pixel
+    pub fn new(writer: W, palette: &[[u8; 3]], width: u16, height: u16, step: u16) -> Self {
+        let step = step.max(1);
+        let cells = width.div_ceil(step) as usize * height.div_ceil(step * 2) as usize;
+
+        Self {
+            writer,
+            palette: palette.to_vec(),
+            transparent: transparent_index(palette),
+            width,
+            height,
+            step,
+            screen: Screen::new(width, height),
+            shown: vec![None; cells],
+            cursor: None,
+            colours: (None, None),
+            controls: false,
+            paused: false,
+            speed: 1.0,
+            finished: false,
+        }
+    }
+
+    /// Enables keyboard controls, putting the terminal in raw mode until the sink is finished
+    pub fn with_controls(mut self) -> Result<Self, Box<dyn Error>> {
+        terminal::enable_raw_mode()?;
+        self.controls = true;
+
+        Ok(self)
+    }
+
+    /// Returns the number of character columns and rows used by the image
+    fn cell_size(&self) -> (u16, u16) {
+        (
+            self.width.div_ceil(self.step),
+            self.height.div_ceil(self.step * 2),
+        )
+    }
+
+    /// Returns the pixel at a position on the screen, if there is one
+    fn pixel(&self, x: u16, y: u16) -> Option<u8> {
+        (y < self.height).then(|| self.screen.pixels[y as usize * self.width as usize + x as usize])
+    }
+
+    /// Draws the character cells which have changed since the last draw
+    fn draw(&mut self) -> Result<(), Box<dyn Error>> {
+        let (cols, rows) = self.cell_size();
+
+        if self.shown.iter().all(Option::is_none) {
+            queue!(self.writer, Hide, ResetColor, Clear(ClearType::All))?;
+        }
+
+        for row in 0..rows {
+            for col in 0..cols {
+                let (x, y) = (col * self.step, row * self.step * 2);
+
+                let cell = (self.pixel(x, y).unwrap(), self.pixel(x, y + self.step));
+                let index = row as usize * cols as usize + col as usize;
+
+                if self.shown[index] == Some(cell) {
+                    continue;
+                }
+
+                self.shown[index] = Some(cell);
+
+                // Move to the cell unless the cursor is already there
+                if self.cursor != Some((col, row)) {
+                    queue!(self.writer, MoveTo(col, row))?;
+                }
+
+                // Set the colours if they have changed
+                let fg = self.colour(cell.0);
+                let bg = cell.1.map_or(Color::Reset, |p| self.colour(p));
+
+                if self.colours.0 != Some(fg) {
+                    queue!(self.writer, SetForegroundColor(fg))?;
+                }
+
+                if self.colours.1 != Some(bg) {
+                    queue!(self.writer, SetBackgroundColor(bg))?;
+                }
+
+                self.colours = (Some(fg), Some(bg));
+
+                queue!(self.writer, Print('\u{2580}'))?;
+                self.cursor = Some((col + 1, row));
+            }
+        }
+
+        self.writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Draws the status line below the image
+    fn draw_status(&mut self) -> Result<(), Box<dyn Error>> {
+        if !self.controls {
+            return Ok(());
+        }
+
+        let status = format!(
+            "{HELP}  speed x{}{}",
+            self.speed,
+            if self.paused { "  PAUSED" } else { "" }
+        );
+
+        let (_, rows) = self.cell_size();
+
+        queue!(
+            self.writer,
+            MoveTo(0, rows),
+            ResetColor,
+            Clear(ClearType::CurrentLine),
+            Print(status)
+        )?;
+
+        self.cursor = None;
+        self.colours = (None, None);
+
+        self.writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Returns the terminal colour for a palette index
+    fn colour(&self, pix: u8) -> Color {
+        let [r, g, b] = self.palette.get(pix as usize).copied().unwrap_or_default();
+
+        Color::Rgb { r, g, b }
+    }
+
+    /// Waits for a number of hundredths of a second at the current speed, handling keys
+    /// if the controls are enabled
+    fn wait(&mut self, delay: u16) -> Result<(), Box<dyn Error>> {
+        let duration = Duration::from_millis(delay as u64 * 10).div_f64(self.speed);
+
+        if !self.controls {
+            thread::sleep(duration);
+            return Ok(());
+        }
+
+        let deadline = Instant::now() + duration;
+
+        loop {
+            // Wait for a key until the deadline, or forever if paused
+            let key = if self.paused {
+                Some(read_key()?)
+            } else {
+                let timeout = deadline.saturating_duration_since(Instant::now());
+
+                if event::poll(timeout)? {
+                    Some(read_key()?)
+                } else {
+                    None
+                }
+            };
+
+            let Some(key) = key else {
+                break;
+            };
+
+            let Some(key) = key else {
+                continue;
+            };
+
+            match key.code {
+                KeyCode::Char(' ') => self.paused = !self.paused,
+                KeyCode::Char('s') | KeyCode::Right => {
+                    // Show the next frame then stop
+                    if self.paused {
+                        break;
+                    }
+
+                    self.paused = true;
+                }
+                KeyCode::Char('+') | KeyCode::Char('=') => {
+                    self.speed = (self.speed * 2.0).min(64.0)
+                }
+                KeyCode::Char('-') => self.speed = (self.speed / 2.0).max(1.0 / 64.0),
+                KeyCode::Char('q') | KeyCode::Esc => Err("Stopped from the keyboard")?,
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Err("Stopped from the keyboard")?
+                }
+                _ => continue,
+            }
+
+            self.draw_status()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> FrameSink for TermSink<W> {
+    fn write_frame(&mut self, frame: &SubFrame) -> Result<(), Box<dyn Error>> {
+        self.screen.apply(frame);
+
+        // Wait for the rest of the frame
+        if frame.continued {
+            return Ok(());
+        }
+
+        let first = self.shown.iter().all(Option::is_none);
+
+        self.draw()?;
+
+        if first {
+            self.draw_status()?;
+        }
+
+        self.wait(frame.delay)
+    }
+
+    fn delay(&mut self, delay: u16) -> Result<(), Box<dyn Error>> {
+        self.wait(delay)
+    }
+
+    fn transparent(&self) -> Option<u8> {
+        self.transparent
+    }
+
+    fn finish(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.finished {
+            return Ok(());
+        }
+
+        self.finished = true;
+
+        // Leave the cursor below the image and status line
+        let (_, rows) = self.cell_size();
+
+        let written = queue!(
+            self.writer,
+            ResetColor,
+            MoveTo(0, rows + self.controls as u16),
+            Show
+        )
+        .and_then(|_| self.writer.flush());
+
+        // Always restore the terminal, even if writing failed
+        if self.controls {
+            terminal::disable_raw_mode()?;
+        }
+
+        Ok(written?)
+    }
+}
+
+impl<W: Write> Drop for TermSink<W> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+/// Reads the next event, returning the key if it was a key press
+fn read_key() -> Result<Option<KeyEvent>, Box<dyn Error>> {
+    Ok(match event::read()? {
+        Event::Key(key) if key.kind == KeyEventKind::Press => Some(key),
+        _ => None,
+    })
+}
@@ -0,0 +1,190 @@
+use super::*;
+
+const PALETTE: [[u8; 3]; 2] = [[0, 0, 0], [255, 255, 255]];
+
+fn sub_frame(left: u16, top: u16, width: u16, height: u16, pixels: &[u8]) -> SubFrame<'_> {
+    SubFrame {
+        left,
+        top,
+        width,
+        height,
+        pixels,
+        delay: 4,
+        continued: false,
+        transparent: None,
+    }
+}
+
+#[test]
+fn test_screen() {
+    let mut screen = Screen::new(3, 2);
+
+    screen.apply(&sub_frame(1, 0, 2, 2, &[1, 2, 3, 4]));
+    assert_eq!(screen.pixels, vec![0, 1, 2, 0, 3, 4]);
+
+    screen.apply(&sub_frame(0, 1, 1, 1, &[5]));
+    assert_eq!(screen.pixels, vec![0, 1, 2, 5, 3, 4]);
+
+    // Transparent pixels are left unchanged
+    let mut frame = sub_frame(0, 0, 3, 1, &[9, 6, 9]);
+    frame.transparent = Some(9);
+    screen.apply(&frame);
+    assert_eq!(screen.pixels, vec![0, 6, 2, 5, 3, 4]);
+}
+
+#[test]
+fn test_y4m() {
+    let mut out = Vec::new();
+
+    let mut sink = Y4mSink::new(&mut out, &PALETTE, 2, 1).unwrap();
+    sink.write_frame(&sub_frame(0, 0, 2, 1, &[0, 1])).unwrap();
+    sink.delay(2).unwrap();
+    sink.finish().unwrap();
+    drop(sink);
+
+    let header = b"YUV4MPEG2 W2 H1 F50:1 Ip A1:1 C444\n";
+    assert!(out.starts_with(header));
+
+    // 6 hundredths of a second at 50 frames per second
+    let frame = b"FRAME\n\x10\xeb\x80\x80\x80\x80";
+    assert_eq!(out.len(), header.len() + 3 * frame.len());
+    assert_eq!(&out[header.len()..header.len() + frame.len()], frame);
+}
+
+#[test]
+fn test_apng() {
+    let mut out = Vec::new();
+
+    let mut sink = ApngSink::new(&mut out, &PALETTE, 2, 2);
+    sink.reserve_transparent();
+    assert_eq!(sink.transparent(), Some(2));
+    assert!(sink.write_frame(&sub_frame(1, 1, 1, 1, &[1])).is_err());
+    sink.write_frame(&sub_frame(0, 0, 2, 2, &[0, 1, 1, 0]))
+        .unwrap();
+    sink.write_frame(&sub_frame(1, 1, 1, 1, &[1])).unwrap();
+    sink.delay(10).unwrap();
+    sink.finish().unwrap();
+    drop(sink);
+
+    let mut reader = png::Decoder::new(&*out).read_info().unwrap();
+    let info = reader.info();
+    assert_eq!((info.width, info.height), (2, 2));
+    assert_eq!(info.palette.as_deref().map(<[u8]>::len), Some(9));
+    assert_eq!(info.trns.as_deref(), Some(&[255, 255, 0][..]));
+    assert_eq!(info.animation_control.unwrap().num_frames, 2);
+
+    let mut buf = vec![0; reader.output_buffer_size()];
+    reader.next_frame(&mut buf).unwrap();
+    assert_eq!(buf[..4], [0, 1, 1, 0]);
+
+    reader.next_frame(&mut buf).unwrap();
+    let control = reader.info().frame_control.unwrap();
+    assert_eq!((control.x_offset, control.y_offset), (1, 1));
+    assert_eq!((control.width, control.height), (1, 1));
+    assert_eq!(control.delay_num, 14);
+    assert_eq!(buf[0], 1);
+}
+
+#[test]
+fn test_unreserved_transparent() {
+    // APNG palette without the transparent entry
+    let mut out = Vec::new();
+
+    let mut sink = ApngSink::new(&mut out, &PALETTE, 1, 1);
+    sink.write_frame(&sub_frame(0, 0, 1, 1, &[1])).unwrap();
+
+    // Too late to change the palette
+    sink.reserve_transparent();
+    assert_eq!(sink.transparent(), None);
+
+    sink.finish().unwrap();
+    drop(sink);
+
+    let reader = png::Decoder::new(&*out).read_info().unwrap();
+    assert_eq!(reader.info().palette.as_deref().map(<[u8]>::len), Some(6));
+    assert!(reader.info().trns.is_none());
+
+    // GIF global colour table size is 2^(n+1) from the logical screen descriptor
+    let table_size = |reserve: bool| {
+        let mut sink = GifSink::new(Vec::new(), &PALETTE, 1, 1).unwrap();
+
+        if reserve {
+            sink.reserve_transparent();
+        }
+
+        sink.write_frame(&sub_frame(0, 0, 1, 1, &[1])).unwrap();
+
+        let out = sink.into_inner().unwrap();
+
+        2 << (out[10] & 7)
+    };
+
+    assert_eq!(table_size(false), 2);
+    assert_eq!(table_size(true), 4);
+}
+
+#[test]
+fn test_term() {
+    let mut out = Vec::new();
+
+    let mut sink = TermSink::new(&mut out, &PALETTE, 2, 3, 1);
+
+    let mut frame = sub_frame(0, 0, 2, 3, &[0, 1, 1, 0, 1, 1]);
+    frame.delay = 0;
+    sink.write_frame(&frame).unwrap();
+
+    // Unchanged cells are not drawn again
+    sink.write_frame(&frame).unwrap();
+
+    frame.pixels = &[0, 1, 1, 0, 1, 0];
+    sink.write_frame(&frame).unwrap();
+    sink.finish().unwrap();
+    drop(sink);
+
+    let out = String::from_utf8(out).unwrap();
+
+    // Two half blocks for the top row, two for the bottom and one for the change
+    assert_eq!(out.matches('\u{2580}').count(), 5);
+
+    // Black over white, white over black, then white over the terminal background on the
+    // odd last row
+    assert!(out.contains("\x1b[38;2;0;0;0m\x1b[48;2;255;255;255m\u{2580}"));
+    assert!(out.contains("\x1b[38;2;255;255;255m\x1b[48;2;0;0;0m\u{2580}"));
+    assert!(out.contains("\x1b[49m\u{2580}"));
+}
+
+/// Writer which fails like a closed pipe
+struct ClosedPipe;
+
+impl Write for ClosedPipe {
+    fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::ErrorKind::BrokenPipe.into())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Err(std::io::ErrorKind::BrokenPipe.into())
+    }
+}
+
+#[test]
+fn test_term_write_error() {
+    let mut sink = TermSink::new(ClosedPipe, &PALETTE, 2, 2, 1);
+
+    // Finishing reports the error once and dropping doesn't retry
+    assert!(sink.finish().is_err());
+    assert!(sink.finish().is_ok());
+}
+
+#[test]
+fn test_format_paths() {
+    assert_eq!("png-dir".parse(), Ok(Format::PngDir));
+    assert!("bmp".parse::<Format>().is_err());
+
+    assert_eq!(Format::from_path("vis/day16.png"), Some(Format::Apng));
+    assert_eq!(Format::from_path("frames/"), Some(Format::PngDir));
+    assert_eq!(Format::from_path("vis/day16"), None);
+
+    assert_eq!(Format::Y4m.path_for("vis/day16.gif"), "vis/day16.y4m");
+    assert_eq!(Format::PngDir.path_for("vis/day16.gif"), "vis/day16");
+    assert_eq!(Format::Term.path_for("vis/day16.gif"), "-");
+}
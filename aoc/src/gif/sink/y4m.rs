@@ -0,0 +1,100 @@
+use std::{error::Error, io::Write};
+
+use super::{transparent_index, FrameSink, Screen, SubFrame};
+
+/// Output frames per second
+const FPS: u64 = 50;
+
+/// Writes frames as an uncompressed YUV4MPEG2 stream at a fixed frame rate. Frames are
+/// repeated to make up their delays, so the stream is best piped straight to an encoder
+pub struct Y4mSink<W: Write> {
+    writer: W,
+    yuv_palette: Vec<[u8; 3]>,
+    transparent: Option<u8>,
+    screen: Screen,
+    planes: Vec<u8>,
+    elapsed: u64,
+    emitted: u64,
+}
+
+impl<W: Write> Y4mSink<W> {
+    /// Creates a Y4M sink with a given palette and size, writing the stream header
+    pub fn new(
+        mut writer: W,
+        palette: &[[u8; 3]],
+        width: u16,
+        height: u16,
+    ) -> Result<Self, Box<dyn Error>> {
+        writeln!(writer, "YUV4MPEG2 W{width} H{height} F{FPS}:1 Ip A1:1 C444")?;
+
+        Ok(Self {
+            writer,
+            yuv_palette: palette.iter().map(rgb_to_yuv).collect(),
+            transparent: transparent_index(palette),
+            screen: Screen::new(width, height),
+            planes: Vec::new(),
+            elapsed: 0,
+            emitted: 0,
+        })
+    }
+
+    /// Advances the clock writing out the current planes for each frame period passed
+    fn advance(&mut self, delay: u16) -> Result<(), Box<dyn Error>> {
+        self.elapsed += delay as u64;
+
+        let due = self.elapsed * FPS / 100;
+
+        while self.emitted < due {
+            self.writer.write_all(b"FRAME\n")?;
+            self.writer.write_all(&self.planes)?;
+
+            self.emitted += 1;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> FrameSink for Y4mSink<W> {
+    fn write_frame(&mut self, frame: &SubFrame) -> Result<(), Box<dyn Error>> {
+        self.screen.apply(frame);
+
+        // Convert the screen to Y, U and V planes
+        let pixels = &self.screen.pixels;
+
+        self.planes.clear();
+        self.planes.reserve(pixels.len() * 3);
+
+        for plane in 0..3 {
+            self.planes
+                .extend(pixels.iter().map(|&p| self.yuv_palette[p as usize][plane]));
+        }
+
+        self.advance(frame.delay)
+    }
+
+    fn delay(&mut self, delay: u16) -> Result<(), Box<dyn Error>> {
+        self.advance(delay)
+    }
+
+    fn transparent(&self) -> Option<u8> {
+        self.transparent
+    }
+
+    fn finish(&mut self) -> Result<(), Box<dyn Error>> {
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Converts an RGB colour to BT.601 limited range YUV
+fn rgb_to_yuv(&[r, g, b]: &[u8; 3]) -> [u8; 3] {
+    let (r, g, b) = (r as i32, g as i32, b as i32);
+
+    [
+        (((66 * r + 129 * g + 25 * b + 128) >> 8) + 16) as u8,
+        (((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128) as u8,
+        (((112 * r - 94 * g - 18 * b + 128) >> 8) + 128) as u8,
+    ]
+}
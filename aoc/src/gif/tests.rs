@@ -0,0 +1,185 @@
+use super::*;
+
+const PALETTE: [[u8; 3]; 3] = [[0, 0, 0], [255, 255, 255], [255, 0, 0]];
+
+#[test]
+fn test_draw_frames() {
+    let mut gif = Gif::from_writer(Vec::new(), &PALETTE, 3, 2, 2, 1).unwrap();
+
+    let mut frame = gif.empty_frame();
+    frame[0][0] = 1;
+    gif.draw_frame(frame.clone(), 5).unwrap();
+
+    frame[1][2] = 2;
+    gif.draw_frame(frame.clone(), 5).unwrap();
+
+    // Identical frames are ignored or turned in to delays
+    gif.draw_frame(frame.clone(), 5).unwrap();
+    gif.draw_frame_identical_check(frame, 7, IdenticalAction::Delay)
+        .unwrap();
+
+    let frames = decode_gif(&gif.into_writer().unwrap()).unwrap();
+    assert_eq!(frames.len(), 3);
+
+    // Frames are scaled up
+    assert_eq!(frames[0].pixels, vec![vec![1, 1, 0, 0, 0, 0], vec![0; 6]]);
+    assert_eq!(frames[0].delay, 5);
+
+    assert_eq!(
+        frames[1].pixels,
+        vec![vec![1, 1, 0, 0, 0, 0], vec![0, 0, 0, 0, 2, 2]]
+    );
+
+    // Delay frames leave the image unchanged
+    assert_eq!(frames[2].pixels, frames[1].pixels);
+    assert_eq!(frames[2].delay, 7);
+}
+
+#[test]
+fn test_scaled_size() {
+    assert_eq!(scaled_size(100, 50, 4, 2, 10).unwrap(), (400, 110));
+
+    // Scaled sizes that don't fit in a u16 are errors rather than overflowing
+    assert!(scaled_size(1000, 10, 100, 1, 0).is_err());
+    assert!(scaled_size(10, 1000, 1, 65, 600).is_err());
+    assert!(Gif::from_writer(Vec::new(), &PALETTE, 1000, 10, 100, 1).is_err());
+}
+
+#[test]
+fn test_minimum_delay() {
+    let mut gif = Gif::from_writer(Vec::new(), &PALETTE, 1, 1, 1, 1).unwrap();
+
+    gif.draw_frame(vec![vec![1]], 0).unwrap();
+    gif.delay(1).unwrap();
+
+    let frames = decode_gif(&gif.into_writer().unwrap()).unwrap();
+    assert_eq!(
+        frames.iter().map(|f| f.delay).collect::<Vec<_>>(),
+        vec![2, 2]
+    );
+}
+
+#[test]
+fn test_split_regions() {
+    let mut gif = Gif::from_writer(Vec::new(), &PALETTE, 20, 20, 1, 1).unwrap();
+    gif.set_split_regions(true);
+
+    let mut frame = gif.empty_frame();
+    gif.draw_frame(frame.clone(), 5).unwrap();
+
+    // Opposite corners change
+    frame[0][0] = 1;
+    frame[19][19] = 2;
+    gif.draw_frame(frame.clone(), 5).unwrap();
+
+    let frames = decode_gif(&gif.into_writer().unwrap()).unwrap();
+    assert_eq!(frames.len(), 3);
+
+    // Second region follows the first immediately
+    assert_eq!(frames[1].delay, 0);
+    assert_eq!(frames[2].delay, 5);
+    assert_eq!(frames[2].pixels, frame);
+}
+
+#[test]
+fn test_transparent_deltas() {
+    let mut gif = Gif::from_writer(Vec::new(), &PALETTE, 4, 1, 1, 1).unwrap();
+    gif.set_transparent_deltas(true);
+
+    gif.draw_frame(vec![vec![1, 1, 1, 1]], 5).unwrap();
+    gif.draw_frame(vec![vec![2, 1, 1, 0]], 5).unwrap();
+
+    let data = gif.into_writer().unwrap();
+
+    // Unchanged pixels use the reserved index after the palette
+    let mut options = ::gif::DecodeOptions::new();
+    options.set_color_output(::gif::ColorOutput::Indexed);
+
+    let mut decoder = options.read_info(&*data).unwrap();
+    decoder.read_next_frame().unwrap();
+
+    let frame = decoder.read_next_frame().unwrap().unwrap();
+    assert_eq!(frame.transparent, Some(3));
+    assert_eq!(frame.dispose, ::gif::DisposalMethod::Keep);
+    assert_eq!(&*frame.buffer, &[2, 3, 3, 0]);
+
+    let frames = decode_gif(&data).unwrap();
+    assert_eq!(frames[1].pixels, vec![vec![2, 1, 1, 0]]);
+}
+
+#[test]
+fn test_caption() {
+    let caption = CaptionBar::new(1, 1, 2);
+    assert_eq!(caption.height(), 7);
+
+    let sink = GifSink::new(Vec::new(), &PALETTE, 6, 4 + caption.height()).unwrap();
+    let mut gif = Gif::with_sink_caption(sink, 3, 2, 2, 2, caption);
+
+    assert_eq!(gif.dimensions(), (3, 2));
+
+    gif.set_caption("1");
+    gif.draw_frame(gif.empty_frame(), 5).unwrap();
+
+    // Changing only the caption still draws a frame
+    gif.set_caption("7");
+    gif.draw_frame(gif.empty_frame(), 5).unwrap();
+
+    gif.draw_frame(gif.empty_frame(), 5).unwrap();
+
+    let frames = decode_gif(&gif.into_writer().unwrap()).unwrap();
+    assert_eq!(frames.len(), 2);
+
+    let bar = |pixels: &[Vec<u8>]| pixels[4..].to_vec();
+
+    // Frames are above the bar and text is inside its border
+    assert_eq!(frames[0].pixels[..4], vec![vec![0; 6]; 4]);
+    assert_eq!(
+        bar(&frames[0].pixels),
+        vec![
+            vec![2, 2, 2, 2, 2, 2],
+            vec![2, 2, 1, 2, 2, 2],
+            vec![2, 1, 1, 2, 2, 2],
+            vec![2, 2, 1, 2, 2, 2],
+            vec![2, 2, 1, 2, 2, 2],
+            vec![2, 1, 1, 1, 2, 2],
+            vec![2, 2, 2, 2, 2, 2],
+        ]
+    );
+
+    assert_eq!(
+        bar(&frames[1].pixels),
+        vec![
+            vec![2, 2, 2, 2, 2, 2],
+            vec![2, 1, 1, 1, 2, 2],
+            vec![2, 2, 2, 1, 2, 2],
+            vec![2, 2, 2, 1, 2, 2],
+            vec![2, 2, 1, 2, 2, 2],
+            vec![2, 2, 1, 2, 2, 2],
+            vec![2, 2, 2, 2, 2, 2],
+        ]
+    );
+}
+
+#[test]
+fn test_max_frames() {
+    let mut gif = Gif::from_writer(Vec::new(), &PALETTE, 1, 1, 1, 1).unwrap();
+    gif.set_max_frames(Some(2));
+
+    for i in 0..3 {
+        gif.draw_frame(vec![vec![i]], 5).unwrap();
+    }
+
+    // Delays still apply to the last frame
+    gif.delay(10).unwrap();
+    gif.draw_frame_identical_check(vec![vec![1]], 20, IdenticalAction::Delay)
+        .unwrap();
+
+    let frames = decode_gif(&gif.into_writer().unwrap()).unwrap();
+    assert_eq!(
+        frames
+            .iter()
+            .map(|f| (f.pixels[0][0], f.delay))
+            .collect::<Vec<_>>(),
+        vec![(0, 5), (1, 5), (1, 10), (1, 20)]
+    );
+}
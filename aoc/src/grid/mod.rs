@@ -0,0 +1,327 @@
+use std::{
+    error,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geom::{Coord, Dir4, Dir8},
+    input::{parse_input, InputSource},
+    Error,
+};
+
+/// Two dimensional grid of tiles stored in a flat vector
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid filled with a value
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Creates a grid from rows of tiles. All rows must be the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Grid rows must all be the same length"
+        );
+
+        Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses a grid from a string, mapping each character to a tile.
+    /// Empty lines are skipped
+    pub fn parse<F>(input: &str, mut tfn: F) -> Result<Self, Error>
+    where
+        F: FnMut(char) -> T,
+    {
+        Self::parse_try(input, |c| Ok::<_, Error>(tfn(c)))
+    }
+
+    /// Parses a grid from a string, mapping each character to a tile with a fallible
+    /// transform. Transform errors are returned as parse errors at the failing character
+    pub fn parse_try<F, E>(input: &str, mut tfn: F) -> Result<Self, Error>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: Into<Box<dyn error::Error>>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (lineno, line) in input.lines().enumerate().filter(|(_, l)| !l.is_empty()) {
+            let lineno = lineno + 1;
+
+            for (column, c) in line.chars().enumerate() {
+                cells.push(
+                    tfn(c).map_err(|e| match Error::from_transform(e.into(), lineno) {
+                        Error::Parse {
+                            line,
+                            column: None,
+                            message,
+                        } => Error::Parse {
+                            line,
+                            column: Some(column + 1),
+                            message,
+                        },
+                        e => e,
+                    })?,
+                );
+            }
+
+            // Check all lines are the same width
+            let line_width = line.chars().count();
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => Err(Error::parse(format!(
+                    "Line is {line_width} characters long, expected {width}"
+                ))
+                .at_line(lineno))?,
+                _ => (),
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.ok_or(Error::EmptyInput)?,
+            height,
+            cells,
+        })
+    }
+
+    /// Loads a grid from an input source, mapping each character to a tile
+    pub fn from_input<'a, F>(source: impl Into<InputSource<'a>>, mut tfn: F) -> Result<Self, Error>
+    where
+        F: FnMut(char) -> T,
+    {
+        parse_input(source, |input| Self::parse(input, &mut tfn))?
+    }
+
+    /// Loads a grid from an input source, mapping each character to a tile with a
+    /// fallible transform
+    pub fn from_input_try<'a, F, E>(
+        source: impl Into<InputSource<'a>>,
+        mut tfn: F,
+    ) -> Result<Self, Error>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: Into<Box<dyn error::Error>>,
+    {
+        parse_input(source, |input| Self::parse_try(input, &mut tfn))?
+    }
+
+    /// Returns the width of the grid
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the grid
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the size of the grid as a coordinate
+    pub fn size(&self) -> Coord<usize> {
+        Coord::new(self.width, self.height)
+    }
+
+    /// Returns true if a position is within the grid
+    pub fn in_bounds(&self, pos: Coord<usize>) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    /// Returns the tile at a position, or None if out of bounds
+    pub fn get(&self, pos: Coord<usize>) -> Option<&T> {
+        if self.in_bounds(pos) {
+            Some(&self.cells[self.index(pos)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the tile at a position, or None if out of bounds
+    pub fn get_mut(&mut self, pos: Coord<usize>) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            let index = self.index(pos);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the tile at a signed position, or None if out of bounds
+    pub fn get_signed(&self, pos: Coord<isize>) -> Option<&T> {
+        self.get(Coord::new(
+            usize::try_from(pos.x).ok()?,
+            usize::try_from(pos.y).ok()?,
+        ))
+    }
+
+    /// Returns the tile at a signed position, wrapping around the edges of the grid. Panics
+    /// if the grid is empty
+    pub fn get_wrapping(&self, pos: Coord<isize>) -> &T {
+        &self[self.wrap(pos)]
+    }
+
+    /// Wraps a signed position around the edges of the grid. Panics if the grid is empty
+    pub fn wrap(&self, pos: Coord<isize>) -> Coord<usize> {
+        self.wrapping_offset(Coord::default(), pos)
+    }
+
+    /// Adds an offset to a position, returning None if the result is out of bounds
+    pub fn offset(&self, pos: Coord<usize>, offset: Coord<isize>) -> Option<Coord<usize>> {
+        pos.checked_add_bounded(offset, self.size())
+    }
+
+    /// Adds an offset to a position, wrapping around the edges of the grid. Panics if the
+    /// grid is empty
+    pub fn wrapping_offset(&self, pos: Coord<usize>, offset: Coord<isize>) -> Coord<usize> {
+        assert!(
+            !self.cells.is_empty(),
+            "Can't wrap a position on an empty grid"
+        );
+
+        pos.add_mod(offset, self.size())
+    }
+
+    /// Returns an iterator over the orthogonal neighbours of a position within the grid,
+    /// clockwise from north
+    pub fn neighbours4(&self, pos: Coord<usize>) -> impl Iterator<Item = Coord<usize>> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |dir| self.offset(pos, dir.offset()))
+    }
+
+    /// Returns an iterator over the orthogonal and diagonal neighbours of a position
+    /// within the grid, clockwise from north
+    pub fn neighbours8(&self, pos: Coord<usize>) -> impl Iterator<Item = Coord<usize>> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.offset(pos, dir.offset()))
+    }
+
+    /// Returns an iterator over the positions from a position in steps of an offset up to
+    /// the edge of the grid. The starting position is not included
+    pub fn ray(
+        &self,
+        pos: Coord<usize>,
+        step: Coord<isize>,
+    ) -> impl Iterator<Item = Coord<usize>> + '_ {
+        std::iter::successors(self.offset(pos, step), move |&pos| self.offset(pos, step))
+    }
+
+    /// Returns a row of the grid as a slice. Panics if the row is out of bounds
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "Row {y} out of bounds");
+
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns an iterator over the rows of the grid
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Returns an iterator over a column of the grid. Panics if the column is out of bounds
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {x} out of bounds");
+
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// Returns an iterator over all positions in the grid in row order
+    pub fn positions(&self) -> impl Iterator<Item = Coord<usize>> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Coord::new(x, y)))
+    }
+
+    /// Returns an iterator over all positions and tiles in the grid in row order
+    pub fn iter(&self) -> impl Iterator<Item = (Coord<usize>, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Returns the position of the first tile matching a predicate
+    pub fn find<P>(&self, mut pred: P) -> Option<Coord<usize>>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().find_map(|(pos, t)| pred(t).then_some(pos))
+    }
+
+    /// Returns the position of the first occurrence of a tile
+    pub fn find_tile(&self, tile: &T) -> Option<Coord<usize>>
+    where
+        T: PartialEq,
+    {
+        self.find(|t| t == tile)
+    }
+
+    /// Returns an iterator over the positions of all occurrences of a tile
+    pub fn find_all<'a>(&'a self, tile: &'a T) -> impl Iterator<Item = Coord<usize>> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter_map(move |(pos, t)| (t == tile).then_some(pos))
+    }
+
+    /// Creates a new grid by mapping each tile
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Converts a position to an index in to the cells vector
+    fn index(&self, pos: Coord<usize>) -> usize {
+        pos.y * self.width + pos.x
+    }
+}
+
+impl<T> Index<Coord<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Coord<usize>) -> &Self::Output {
+        assert!(self.in_bounds(pos), "Position {pos:?} out of bounds");
+
+        &self.cells[self.index(pos)]
+    }
+}
+
+impl<T> IndexMut<Coord<usize>> for Grid<T> {
+    fn index_mut(&mut self, pos: Coord<usize>) -> &mut Self::Output {
+        assert!(self.in_bounds(pos), "Position {pos:?} out of bounds");
+
+        let index = self.index(pos);
+        &mut self.cells[index]
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,114 @@
+use super::*;
+
+fn c<T>(x: T, y: T) -> Coord<T> {
+    Coord::new(x, y)
+}
+
+const EXAMPLE: &str = "\
+#..
+.S#
+..E
+";
+
+fn example() -> Grid<char> {
+    Grid::parse(EXAMPLE, |c| c).unwrap()
+}
+
+#[test]
+fn test_parse() {
+    let grid = example();
+
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid.height(), 3);
+    assert_eq!(grid[c(2, 1)], '#');
+    assert_eq!(grid.row(1), &['.', 'S', '#']);
+    assert_eq!(grid.column(2).collect::<String>(), ".#E");
+    assert_eq!(grid.find_tile(&'S'), Some(c(1, 1)));
+    assert_eq!(
+        grid.find_all(&'#').collect::<Vec<_>>(),
+        vec![c(0, 0), c(2, 1)]
+    );
+
+    let err = Grid::parse("##\n#\n", |c| c).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parse error at line 2: Line is 1 characters long, expected 2"
+    );
+
+    let err = Grid::parse_try("..\n.x\n", |c| match c {
+        '.' => Ok(0),
+        _ => Err(format!("Invalid tile '{c}'")),
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parse error at line 2, column 2: Invalid tile 'x'"
+    );
+
+    assert!(matches!(Grid::parse("\n", |c| c), Err(Error::EmptyInput)));
+}
+
+#[test]
+fn test_indexing() {
+    let grid = example();
+
+    assert_eq!(grid.get(c(3, 0)), None);
+    assert_eq!(grid.get_signed(c(-1, 0)), None);
+    assert_eq!(grid.get_signed(c(2, 2)), Some(&'E'));
+    assert_eq!(grid.get_wrapping(c(-1, -1)), &'E');
+    assert_eq!(grid.get_wrapping(c(4, 3)), &'.');
+    assert_eq!(grid.offset(c(0, 0), c(-1, 0)), None);
+    assert_eq!(grid.offset(c(0, 0), c(1, 2)), Some(c(1, 2)));
+    assert_eq!(grid.wrapping_offset(c(0, 0), c(-1, 4)), c(2, 1));
+}
+
+#[test]
+#[should_panic(expected = "Column 3 out of bounds")]
+fn test_column_out_of_bounds() {
+    example().column(3).count();
+}
+
+#[test]
+#[should_panic(expected = "Row 3 out of bounds")]
+fn test_row_out_of_bounds() {
+    example().row(3);
+}
+
+#[test]
+#[should_panic(expected = "Can't wrap a position on an empty grid")]
+fn test_wrap_empty() {
+    Grid::new(0, 3, '.').wrap(c(1, 1));
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = example();
+
+    assert_eq!(
+        grid.neighbours4(c(0, 0)).collect::<Vec<_>>(),
+        vec![c(1, 0), c(0, 1)]
+    );
+    assert_eq!(grid.neighbours4(c(1, 1)).count(), 4);
+    assert_eq!(
+        grid.neighbours8(c(0, 0)).collect::<Vec<_>>(),
+        vec![c(1, 0), c(1, 1), c(0, 1)]
+    );
+    assert_eq!(grid.neighbours8(c(1, 1)).count(), 8);
+}
+
+#[test]
+fn test_rays() {
+    let grid = example();
+
+    assert_eq!(
+        grid.ray(c(0, 0), Dir8::SE.offset())
+            .map(|p| grid[p])
+            .collect::<String>(),
+        "SE"
+    );
+    assert_eq!(
+        grid.ray(c(0, 1), c(1, 0)).collect::<Vec<_>>(),
+        vec![c(1, 1), c(2, 1)]
+    );
+    assert_eq!(grid.ray(c(0, 0), c(0, -1)).count(), 0);
+}
@@ -1,61 +1,117 @@
-use std::{error::Error, fs::File};
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
 
 use memmap2::Mmap;
 
-/// Memory mapped input
-pub struct Input {
-    mmap: Mmap,
+use super::{
+    numbered_lines,
+    section::Sections,
+    sections,
+    source::{InputSource, INPUT_DIR_VAR},
+};
+use crate::Error;
+
+/// Puzzle input, memory mapped when read from a file
+pub struct Input<'a> {
+    data: Data<'a>,
+}
+
+/// Input data storage
+enum Data<'a> {
+    Mmap(Mmap),
+    Owned(Vec<u8>),
+    Borrowed(&'a [u8]),
 }
 
-impl Input {
-    /// Opens and memory maps an input file for a given day
-    pub fn new(day: usize) -> Result<Self, Box<dyn Error>> {
-        let file = Self::open(&format!("day{day:02}.txt"))?;
+impl<'a> Input<'a> {
+    /// Opens an input source, memory mapping it if it is a file
+    pub fn new(source: InputSource<'a>) -> Result<Self, Error> {
+        let data = match source {
+            InputSource::Day(day) => Data::Mmap(Self::map(Self::open_day(day)?)?),
+            InputSource::Path(path) => Data::Mmap(Self::map(Self::open_path(&path)?)?),
+            InputSource::Stdin => {
+                let mut buf = Vec::new();
+                io::stdin().read_to_end(&mut buf)?;
+                Data::Owned(buf)
+            }
+            InputSource::Str(str) => Data::Borrowed(str.as_bytes()),
+        };
 
-        Self::new_from_file(file)
+        Ok(Self { data })
     }
 
-    /// Returns the memory mapped file as a lines iterator
-    pub fn lines(&self) -> impl Iterator<Item = &str> {
-        self.mmap
-            .as_ref()
-            .split(|&b| b == b'\n')
-            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
-            .filter(|line| !line.is_empty())
-            .map(|line| {
-                #[cfg(debug_assertions)]
-                let str = std::str::from_utf8(line).expect("Line is not valid UTF-8");
+    /// Returns the input as a lines iterator. Empty lines are skipped
+    pub fn lines(&self) -> Result<impl Iterator<Item = &str>, Error> {
+        Ok(numbered_lines(self.as_str()?).map(|(_, line)| line))
+    }
 
-                #[cfg(not(debug_assertions))]
-                let str = unsafe { std::str::from_utf8_unchecked(line) };
+    /// Returns the input as an iterator of 1 based line numbers and lines. Empty lines are skipped
+    pub fn numbered_lines(&self) -> Result<impl Iterator<Item = (usize, &str)>, Error> {
+        Ok(numbered_lines(self.as_str()?))
+    }
 
-                str
-            })
+    /// Returns an iterator over the blank line separated sections of the input
+    pub fn sections(&self) -> Result<Sections<'_>, Error> {
+        Ok(sections(self.as_str()?))
     }
 
-    /// Returns the memory mapped file as a string slice
-    pub fn as_str(&self) -> Result<&str, Box<dyn Error>> {
-        #[cfg(debug_assertions)]
-        let str = std::str::from_utf8(self.mmap.as_ref())?;
+    /// Returns the input as a string slice
+    pub fn as_str(&self) -> Result<&str, Error> {
+        std::str::from_utf8(self.bytes()).map_err(|e| {
+            // Locate the invalid byte
+            let valid = &self.bytes()[..e.valid_up_to()];
+            let line_start = valid.iter().rposition(|&b| b == b'\n').map_or(0, |p| p + 1);
 
-        #[cfg(not(debug_assertions))]
-        let str = unsafe { std::str::from_utf8_unchecked(self.mmap.as_ref()) };
+            Error::InvalidUtf8 {
+                line: valid.iter().filter(|&&b| b == b'\n').count() + 1,
+                column: valid.len() - line_start + 1,
+            }
+        })
+    }
 
-        Ok(str)
+    /// Returns the raw input bytes
+    fn bytes(&self) -> &[u8] {
+        match &self.data {
+            Data::Mmap(mmap) => mmap.as_ref(),
+            Data::Owned(buf) => buf,
+            Data::Borrowed(bytes) => bytes,
+        }
     }
 
-    /// Opens the input file
-    fn open(file: &str) -> std::io::Result<File> {
-        match File::open(format!("inputs/{file}")) {
-            Err(_) => File::open(format!("../inputs/{file}")),
+    /// Opens the input file for a day
+    fn open_day(day: usize) -> Result<File, Error> {
+        let file = format!("day{day:02}.txt");
+
+        // Use the configured input directory if there is one
+        if let Some(dir) = std::env::var_os(INPUT_DIR_VAR) {
+            return Self::open_path(&PathBuf::from(dir).join(file));
+        }
+
+        // Try inputs/ then ../inputs/, reporting inputs/ if neither exist
+        match Self::open_path(&Path::new("inputs").join(&file)) {
+            Err(Error::MissingFile(path)) => {
+                match Self::open_path(&Path::new("../inputs").join(&file)) {
+                    Err(Error::MissingFile(_)) => Err(Error::MissingFile(path)),
+                    f => f,
+                }
+            }
             f => f,
         }
     }
 
-    /// Memory maps an open file
-    fn new_from_file(file: File) -> Result<Self, Box<dyn Error>> {
-        let mmap = unsafe { Mmap::map(&file)? };
+    /// Opens an input file by path
+    fn open_path(path: &Path) -> Result<File, Error> {
+        File::open(path).map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => Error::MissingFile(path.to_path_buf()),
+            _ => Error::Io(e),
+        })
+    }
 
-        Ok(Self { mmap })
+    /// Memory maps an open file
+    fn map(file: File) -> Result<Mmap, Error> {
+        Ok(unsafe { Mmap::map(&file)? })
     }
 }
@@ -1,39 +1,100 @@
-use std::error::Error;
+use std::error;
+
+use crate::Error;
 
 mod mmap;
-use mmap::Input;
+pub use mmap::Input;
+
+mod section;
+pub use section::{Section, Sections};
 
-/// Parse whole input file with with a given transform
-pub fn parse_input<T, F>(day: usize, mut tfn: F) -> Result<T, Box<dyn Error>>
+mod source;
+pub use source::{InputSource, INPUT_DIR_VAR};
+
+/// Parse whole input with with a given transform.
+/// The source can be a day number or any other `InputSource`
+pub fn parse_input<'a, T, F>(source: impl Into<InputSource<'a>>, mut tfn: F) -> Result<T, Error>
 where
     F: FnMut(&str) -> T,
 {
-    Ok(tfn(Input::new(day)?.as_str()?))
+    Ok(tfn(Input::new(source.into())?.as_str()?))
 }
 
-/// Parse an input file line by line to a vector with a given transform
-pub fn parse_input_vec<T, F>(day: usize, tfn: F) -> Result<Vec<T>, Box<dyn Error>>
+/// Parse an input line by line to a vector with a given transform
+pub fn parse_input_vec<'a, T, F>(
+    source: impl Into<InputSource<'a>>,
+    tfn: F,
+) -> Result<Vec<T>, Error>
 where
     F: FnMut(&str) -> T,
 {
-    Ok(Input::new(day)?.lines().map(tfn).collect())
+    Ok(Input::new(source.into())?.lines()?.map(tfn).collect())
+}
+
+/// Parse an input line by line to a vector with a fallible transform.
+/// Transform errors are returned as parse errors at the failing line
+pub fn parse_input_vec_try<'a, T, E, F>(
+    source: impl Into<InputSource<'a>>,
+    mut tfn: F,
+) -> Result<Vec<T>, Error>
+where
+    F: FnMut(&str) -> Result<T, E>,
+    E: Into<Box<dyn error::Error>>,
+{
+    Input::new(source.into())?
+        .numbered_lines()?
+        .map(|(lineno, line)| tfn(line).map_err(|e| Error::from_transform(e.into(), lineno)))
+        .collect()
+}
+
+/// Parse an input section by section to a vector with a given transform.
+/// Sections are separated by one or more blank lines
+pub fn parse_input_sections<'a, T, F>(
+    source: impl Into<InputSource<'a>>,
+    tfn: F,
+) -> Result<Vec<T>, Error>
+where
+    F: FnMut(Section) -> T,
+{
+    Ok(Input::new(source.into())?.sections()?.map(tfn).collect())
 }
 
-/// Parse an input file with a single line with a given transform
-pub fn parse_input_line<T, F>(day: usize, mut tfn: F) -> Result<T, Box<dyn Error>>
+/// Parse an input with a single line with a given transform
+pub fn parse_input_line<'a, T, F>(
+    source: impl Into<InputSource<'a>>,
+    mut tfn: F,
+) -> Result<T, Error>
 where
     F: FnMut(&str) -> T,
 {
-    Ok(tfn(Input::new(day)?
-        .lines()
+    Ok(tfn(Input::new(source.into())?
+        .lines()?
         .next()
-        .expect("No line in input")))
+        .ok_or(Error::EmptyInput)?))
 }
 
 /// Parse an input string to a vector with a given transform
-pub fn parse_test_vec<T, F>(test: &str, tfn: F) -> Result<Vec<T>, Box<dyn Error>>
+pub fn parse_test_vec<T, F>(test: &str, tfn: F) -> Result<Vec<T>, Error>
 where
     F: FnMut(&str) -> T,
 {
     Ok(test.lines().map(tfn).collect())
 }
+
+/// Returns an iterator over the blank line separated sections of a string
+pub fn sections(input: &str) -> Sections<'_> {
+    Sections::new(input)
+}
+
+/// Splits a string into 1 based line numbers and lines, stripping carriage returns
+/// and skipping empty lines
+fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .split('\n')
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.strip_suffix('\r').unwrap_or(line)))
+        .filter(|(_, line)| !line.is_empty())
+}
+
+#[cfg(test)]
+mod tests;
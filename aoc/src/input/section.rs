@@ -0,0 +1,100 @@
+use super::numbered_lines;
+
+/// A block of input lines separated from other blocks by blank lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    text: &'a str,
+    line: usize,
+}
+
+impl<'a> Section<'a> {
+    /// Returns the section as a string slice, without the surrounding blank lines
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// Returns the 1 based line number of the first line of the section in the input
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the section as a lines iterator
+    pub fn lines(&self) -> impl Iterator<Item = &'a str> {
+        numbered_lines(self.text).map(|(_, line)| line)
+    }
+
+    /// Returns the section as an iterator of 1 based input line numbers and lines
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a str)> {
+        let first = self.line - 1;
+
+        numbered_lines(self.text).map(move |(lineno, line)| (first + lineno, line))
+    }
+}
+
+/// Iterator over the blank line separated sections of an input
+pub struct Sections<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+impl<'a> Sections<'a> {
+    pub(super) fn new(input: &'a str) -> Self {
+        Self {
+            rest: input,
+            line: 1,
+        }
+    }
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = Section<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip blank lines
+        loop {
+            if self.rest.is_empty() {
+                return None;
+            }
+
+            let (next, rest) = split_line(self.rest);
+
+            if !next.is_empty() {
+                break;
+            }
+
+            self.rest = rest;
+            self.line += 1;
+        }
+
+        let text = self.rest;
+        let line = self.line;
+        let mut end = 0;
+
+        // Consume lines up to the next blank line
+        while !self.rest.is_empty() {
+            let (next, rest) = split_line(self.rest);
+
+            if next.is_empty() {
+                break;
+            }
+
+            end = text.len() - self.rest.len() + next.len();
+
+            self.rest = rest;
+            self.line += 1;
+        }
+
+        Some(Section {
+            text: &text[..end],
+            line,
+        })
+    }
+}
+
+/// Splits the first line from an input, returning the line without its line ending
+/// and the input following it
+fn split_line(input: &str) -> (&str, &str) {
+    let (line, rest) = input.split_once('\n').unwrap_or((input, ""));
+
+    (line.strip_suffix('\r').unwrap_or(line), rest)
+}
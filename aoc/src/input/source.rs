@@ -0,0 +1,42 @@
+use std::path::PathBuf;
+
+/// Environment variable naming a directory to read day input files from
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where puzzle input is read from
+#[derive(Debug, Clone)]
+pub enum InputSource<'a> {
+    /// The input file for a day. This is `dayNN.txt` in the directory named by
+    /// the `AOC_INPUT_DIR` environment variable if set, otherwise in `inputs/`
+    /// or `../inputs/`
+    Day(usize),
+    /// An explicit file path
+    Path(PathBuf),
+    /// Standard input
+    Stdin,
+    /// An in-memory string
+    Str(&'a str),
+}
+
+impl InputSource<'_> {
+    /// Creates a source from a command line argument. `-` reads from standard input,
+    /// anything else is a file path
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(path.into()),
+        }
+    }
+}
+
+impl From<usize> for InputSource<'_> {
+    fn from(day: usize) -> Self {
+        InputSource::Day(day)
+    }
+}
+
+impl From<PathBuf> for InputSource<'_> {
+    fn from(path: PathBuf) -> Self {
+        InputSource::Path(path)
+    }
+}
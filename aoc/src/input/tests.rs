@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+
+use super::*;
+
+const EXAMPLE: &str = "first\r\n\nsecond\nthird\n";
+
+#[test]
+fn test_str_source() {
+    assert_eq!(
+        parse_input(InputSource::Str(EXAMPLE), |s| s.len()).unwrap(),
+        EXAMPLE.len()
+    );
+
+    assert_eq!(
+        parse_input_vec(InputSource::Str(EXAMPLE), |s| s.to_string()).unwrap(),
+        vec!["first", "second", "third"]
+    );
+
+    assert_eq!(
+        parse_input_line(InputSource::Str(EXAMPLE), |s| s.to_string()).unwrap(),
+        "first"
+    );
+}
+
+#[test]
+fn test_path_source() {
+    let path = std::env::temp_dir().join("aoc_input_test_path_source.txt");
+    std::fs::write(&path, EXAMPLE).unwrap();
+
+    assert_eq!(
+        parse_input_vec(path.clone(), |s| s.to_string()).unwrap(),
+        vec!["first", "second", "third"]
+    );
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(parse_input_vec(path, |s| s.to_string()).is_err());
+}
+
+#[test]
+fn test_try_transform() {
+    assert_eq!(
+        parse_input_vec_try(InputSource::Str("1\n\n2\n3\n"), |s| s.parse::<u8>()).unwrap(),
+        vec![1, 2, 3]
+    );
+
+    let err =
+        parse_input_vec_try(InputSource::Str("1\n\nx\n3\n"), |s| s.parse::<u8>()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parse error at line 3: invalid digit found in string"
+    );
+
+    let err = parse_input_vec_try(InputSource::Str("12\n1x\n"), |s| {
+        match s.find(|c: char| !c.is_ascii_digit()) {
+            Some(pos) => Err(Error::parse_at(pos + 1, "Not a digit")),
+            None => Ok(s.len()),
+        }
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parse error at line 2, column 2: Not a digit"
+    );
+}
+
+#[test]
+fn test_errors() {
+    assert!(matches!(
+        parse_input_line(InputSource::Str("\r\n\n"), |s| s.to_string()),
+        Err(Error::EmptyInput)
+    ));
+
+    assert!(matches!(
+        parse_input(PathBuf::from("no/such/file.txt"), |s| s.to_string()),
+        Err(Error::MissingFile(_))
+    ));
+
+    let path = std::env::temp_dir().join("aoc_input_test_errors.txt");
+    std::fs::write(&path, b"ok\nab\xffc\n").unwrap();
+
+    let result = parse_input_vec(path.clone(), |s| s.to_string());
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(matches!(
+        result,
+        Err(Error::InvalidUtf8 { line: 2, column: 3 })
+    ));
+}
+
+#[test]
+fn test_sections() {
+    let input = "\r\na|b\r\nc|d\r\n\r\n\r\n1,2\r\n3,4\r\n\r\n";
+
+    let sections = parse_input_sections(InputSource::Str(input), |section| {
+        (
+            section.line(),
+            section.as_str().to_string(),
+            section
+                .numbered_lines()
+                .map(|(n, line)| (n, line.to_string()))
+                .collect::<Vec<_>>(),
+        )
+    })
+    .unwrap();
+
+    assert_eq!(
+        sections,
+        vec![
+            (
+                2,
+                "a|b\r\nc|d".to_string(),
+                vec![(2, "a|b".to_string()), (3, "c|d".to_string())]
+            ),
+            (
+                6,
+                "1,2\r\n3,4".to_string(),
+                vec![(6, "1,2".to_string()), (7, "3,4".to_string())]
+            ),
+        ]
+    );
+
+    let mut iter = super::sections("x\n\ny");
+    assert_eq!(iter.next().unwrap().lines().collect::<Vec<_>>(), vec!["x"]);
+    assert_eq!(iter.next().unwrap().lines().collect::<Vec<_>>(), vec!["y"]);
+    assert!(iter.next().is_none());
+
+    assert!(super::sections("\n\r\n").next().is_none());
+}
@@ -1,4 +1,18 @@
 #[cfg(feature = "gif")]
 pub mod gif;
 
+pub mod answers;
+pub mod geom;
+pub mod grid;
+
+mod error;
+pub use error::Error;
+
 pub mod input;
+pub mod observer;
+pub mod params;
+pub mod search;
+pub mod solution;
+
+#[cfg(feature = "gif")]
+pub mod vis;
@@ -0,0 +1,22 @@
+/// Receives typed events emitted by a solver as it runs
+pub trait Observer<E> {
+    /// Handles an event
+    fn event(&mut self, event: E);
+}
+
+/// Ignores all events. Pass `&mut ()` to run a solver unobserved
+impl<E> Observer<E> for () {
+    #[inline(always)]
+    fn event(&mut self, _event: E) {}
+}
+
+/// Closures taking an event can be used as observers
+impl<E, F> Observer<E> for F
+where
+    F: FnMut(E),
+{
+    #[inline]
+    fn event(&mut self, event: E) {
+        self(event)
+    }
+}
@@ -0,0 +1,121 @@
+use std::{error::Error, fs, str::FromStr};
+
+/// Puzzle parameter override as a name and value
+pub type Override = (String, String);
+
+/// Parameter override with the day it applies to, or None for every day
+pub type DayOverride = (Option<usize>, Override);
+
+/// Parses a `name=value` parameter override from the command line
+pub fn parse_override(arg: &str) -> Result<Override, Box<dyn Error>> {
+    match arg.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!("Parameter '{arg}' should be name=value"))?,
+    }
+}
+
+/// Parses a parameter override from the command line which may be limited to a day, as
+/// `name=value` or `day:name=value`
+pub fn parse_day_override(arg: &str) -> Result<DayOverride, Box<dyn Error>> {
+    let (day, param) = match arg.split_once(':') {
+        Some((day, param)) if !day.contains('=') => {
+            let day = day
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid day in parameter '{arg}'"))?;
+
+            (Some(day), param)
+        }
+        _ => (None, arg),
+    };
+
+    Ok((day, parse_override(param)?))
+}
+
+/// Loads the parameter overrides for a day from a config file
+pub fn load_config(file: &str, day: usize) -> Result<Vec<Override>, Box<dyn Error>> {
+    Ok(for_day(&load_config_days(file)?, day))
+}
+
+/// Loads the parameter overrides for all days from a config file
+pub fn load_config_days(file: &str) -> Result<Vec<DayOverride>, Box<dyn Error>> {
+    let text = fs::read_to_string(file).map_err(|e| format!("{file}: {e}"))?;
+
+    parse_config_days(&text).map_err(|e| format!("{file}: {e}").into())
+}
+
+/// Parses the parameter overrides for a day from a config file
+pub fn parse_config(text: &str, day: usize) -> Result<Vec<Override>, Box<dyn Error>> {
+    Ok(for_day(&parse_config_days(text)?, day))
+}
+
+/// Parses the parameter overrides for all days from a config file. Each override is a
+/// `name = value` line. Lines after a `[dayNN]` header only apply to that day, and lines
+/// before any header apply to every day
+pub fn parse_config_days(text: &str) -> Result<Vec<DayOverride>, Box<dyn Error>> {
+    let mut overrides = Vec::new();
+    let mut section = None;
+
+    for (lineno, line) in text.lines().enumerate() {
+        let line = line.trim();
+
+        // Skip blank lines and comments
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let err = |msg: &str| format!("line {}: {msg}", lineno + 1);
+
+        // Section header
+        if let Some(header) = line.strip_prefix('[') {
+            let header_day = header
+                .strip_suffix(']')
+                .and_then(|h| h.trim().strip_prefix("day"))
+                .and_then(|d| d.parse::<usize>().ok())
+                .ok_or_else(|| err("expected [dayNN]"))?;
+
+            section = Some(header_day);
+            continue;
+        }
+
+        let (name, value) = parse_override(line).map_err(|_| err("expected name = value"))?;
+
+        overrides.push((section, (name, unquote(&value).to_string())));
+    }
+
+    Ok(overrides)
+}
+
+/// Returns the overrides which apply to a day
+pub fn for_day(overrides: &[DayOverride], day: usize) -> Vec<Override> {
+    overrides
+        .iter()
+        .filter(|(d, _)| d.is_none_or(|d| d == day))
+        .map(|(_, o)| o.clone())
+        .collect()
+}
+
+/// Parses a parameter value
+pub fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T, Box<dyn Error>> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value '{value}' for parameter '{name}'").into())
+}
+
+/// Returns the error for a parameter name a solution does not have
+pub fn unknown(name: &str) -> Box<dyn Error> {
+    format!("Unknown parameter '{name}'").into()
+}
+
+/// Removes quotes from around a value
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,89 @@
+use super::*;
+
+fn owned(overrides: &[(&str, &str)]) -> Vec<Override> {
+    overrides
+        .iter()
+        .map(|(n, v)| (n.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_parse_override() {
+    assert_eq!(
+        parse_override("dim=6").unwrap(),
+        ("dim".to_string(), "6".to_string())
+    );
+    assert_eq!(
+        parse_override(" bytes = 12 ").unwrap(),
+        ("bytes".to_string(), "12".to_string())
+    );
+
+    assert!(parse_override("dim").is_err());
+    assert!(parse_override("=6").is_err());
+}
+
+#[test]
+fn test_parse_day_override() {
+    assert_eq!(
+        parse_day_override("18:dim=6").unwrap(),
+        (Some(18), ("dim".to_string(), "6".to_string()))
+    );
+    assert_eq!(
+        parse_day_override("dim=6").unwrap(),
+        (None, ("dim".to_string(), "6".to_string()))
+    );
+    assert_eq!(
+        parse_day_override("sep=a:b").unwrap(),
+        (None, ("sep".to_string(), "a:b".to_string()))
+    );
+
+    assert!(parse_day_override("x:dim=6").is_err());
+    assert!(parse_day_override("18:dim").is_err());
+}
+
+#[test]
+fn test_parse_config() {
+    let text = "\
+# Shared
+seconds = 50
+
+[day18]
+dim = 6
+bytes = \"12\"
+
+[day14]
+width = 11
+";
+
+    assert_eq!(
+        parse_config(text, 18).unwrap(),
+        owned(&[("seconds", "50"), ("dim", "6"), ("bytes", "12")])
+    );
+    assert_eq!(
+        parse_config(text, 14).unwrap(),
+        owned(&[("seconds", "50"), ("width", "11")])
+    );
+    assert_eq!(parse_config(text, 1).unwrap(), owned(&[("seconds", "50")]));
+
+    assert_eq!(
+        parse_config_days(text).unwrap()[..2],
+        [
+            (None, ("seconds".to_string(), "50".to_string())),
+            (Some(18), ("dim".to_string(), "6".to_string()))
+        ]
+    );
+
+    assert!(parse_config("[18]", 18).is_err());
+    assert!(parse_config("[day18", 18).is_err());
+    assert!(parse_config("dim 6", 18).is_err());
+}
+
+#[test]
+fn test_parse_value() {
+    assert_eq!(parse_value::<usize>("dim", "70").unwrap(), 70);
+    assert!(parse_value::<u8>("blinks", "256").is_err());
+    assert_eq!(
+        unknown("size").to_string(),
+        "Unknown parameter 'size'".to_string()
+    );
+}
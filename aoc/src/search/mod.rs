@@ -0,0 +1,376 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use fxhash::{FxHashMap, FxHashSet};
+
+/// Result of a search holding the cost to and predecessors of each state reached
+#[derive(Debug, Clone)]
+pub struct SearchResult<S, C> {
+    costs: FxHashMap<S, C>,
+    preds: FxHashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S, C> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord,
+{
+    /// Creates a result containing just the start state
+    fn new(start: S, cost: C) -> Self {
+        let mut costs = FxHashMap::default();
+        costs.insert(start, cost);
+
+        Self {
+            costs,
+            preds: FxHashMap::default(),
+            goals: Vec::new(),
+        }
+    }
+
+    /// Returns the first goal state reached
+    pub fn goal(&self) -> Option<&S> {
+        self.goals.first()
+    }
+
+    /// Returns the goal states reached. All goal states at the optimal cost are returned
+    /// when searching for all optimal paths, otherwise only the first
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// Returns the cost of reaching the goal
+    pub fn cost(&self) -> Option<C> {
+        self.cost_to(self.goal()?)
+    }
+
+    /// Returns the best known cost of reaching a state
+    pub fn cost_to(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// Returns the best known costs of all states reached
+    pub fn costs(&self) -> &FxHashMap<S, C> {
+        &self.costs
+    }
+
+    /// Consumes the result returning the best known costs of all states reached
+    pub fn into_costs(self) -> FxHashMap<S, C> {
+        self.costs
+    }
+
+    /// Returns the predecessors of a state on its best paths
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.preds.get(state).map_or(&[], |preds| preds)
+    }
+
+    /// Returns a best path from the start state to the goal, inclusive
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goal()?)
+    }
+
+    /// Returns a best path from the start state to a state, inclusive
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.costs.contains_key(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+
+        // Follow the first predecessor back to the start
+        while let Some(pred) = self.predecessors(&path[path.len() - 1]).first() {
+            path.push(pred.clone());
+        }
+
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Returns every optimal path from the start state to a goal state, inclusive.
+    /// Needs a search for all optimal paths to return more than one
+    pub fn all_paths(&self) -> Vec<Vec<S>> {
+        let mut paths = Vec::new();
+
+        for goal in &self.goals {
+            self.collect_paths(vec![goal.clone()], &mut paths);
+        }
+
+        paths
+    }
+
+    /// Returns the set of states on any optimal path to a goal state.
+    /// Needs a search for all optimal paths to include more than one path
+    pub fn optimal_states(&self) -> FxHashSet<S> {
+        let mut states = FxHashSet::default();
+        let mut work = self.goals.clone();
+
+        while let Some(state) = work.pop() {
+            if states.insert(state.clone()) {
+                work.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+
+        states
+    }
+
+    /// Extends a reversed partial path back to the start through every predecessor
+    fn collect_paths(&self, mut path: Vec<S>, paths: &mut Vec<Vec<S>>) {
+        match self.predecessors(&path[path.len() - 1]) {
+            [] => {
+                path.reverse();
+                paths.push(path);
+            }
+            [preds @ .., last] => {
+                for pred in preds {
+                    let mut path = path.clone();
+                    path.push(pred.clone());
+                    self.collect_paths(path, paths);
+                }
+
+                path.push(last.clone());
+                self.collect_paths(path, paths);
+            }
+        }
+    }
+
+    /// Records a step to a state. Returns true if the state has a new best cost and
+    /// needs to be expanded
+    fn relax(&mut self, from: &S, to: &S, cost: C, all: bool) -> bool {
+        match self.costs.get(to).map(|best| cost.cmp(best)) {
+            None | Some(Ordering::Less) => {
+                self.costs.insert(to.clone(), cost);
+                self.preds.insert(to.clone(), vec![from.clone()]);
+                true
+            }
+            Some(Ordering::Equal) if all => {
+                self.preds.entry(to.clone()).or_default().push(from.clone());
+                false
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Breadth first search from a start state, stopping at the first goal state.
+/// Every step costs 1
+pub fn bfs<S, N, I, G>(start: S, neighbours: N, is_goal: G) -> SearchResult<S, usize>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    breadth_first(start, neighbours, is_goal, false)
+}
+
+/// Breadth first search from a start state recording all optimal predecessors of
+/// each state. Finds every goal state at the optimal distance
+pub fn bfs_all<S, N, I, G>(start: S, neighbours: N, is_goal: G) -> SearchResult<S, usize>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    breadth_first(start, neighbours, is_goal, true)
+}
+
+/// Dijkstra search from a start state, stopping at the first goal state.
+/// Neighbours are returned with the cost of the step to them
+pub fn dijkstra<S, C, N, I, G>(start: S, neighbours: N, is_goal: G) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    best_first(start, neighbours, |_| C::default(), is_goal, false)
+}
+
+/// Dijkstra search from a start state recording all optimal predecessors of each
+/// state. Finds every goal state at the optimal cost
+pub fn dijkstra_all<S, C, N, I, G>(start: S, neighbours: N, is_goal: G) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    best_first(start, neighbours, |_| C::default(), is_goal, true)
+}
+
+/// A* search from a start state, stopping at the first goal state. The heuristic
+/// must never overestimate the remaining cost to a goal
+pub fn astar<S, C, N, I, H, G>(
+    start: S,
+    neighbours: N,
+    heuristic: H,
+    is_goal: G,
+) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    best_first(start, neighbours, heuristic, is_goal, false)
+}
+
+fn breadth_first<S, N, I, G>(
+    start: S,
+    mut neighbours: N,
+    mut is_goal: G,
+    all: bool,
+) -> SearchResult<S, usize>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut result = SearchResult::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+    let mut best = None;
+
+    while let Some(state) = queue.pop_front() {
+        let cost = result.costs[&state];
+
+        // Finished if further than the best goal
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+
+        // Reached a goal?
+        if is_goal(&state) {
+            best = Some(cost);
+            result.goals.push(state);
+
+            if !all {
+                break;
+            }
+
+            continue;
+        }
+
+        // Step to each neighbour
+        for next in neighbours(&state) {
+            if result.relax(&state, &next, cost + 1, all) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    result
+}
+
+fn best_first<S, C, N, I, H, G>(
+    start: S,
+    mut neighbours: N,
+    mut heuristic: H,
+    mut is_goal: G,
+    all: bool,
+) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut result = SearchResult::new(start.clone(), C::default());
+    let mut queue = BinaryHeap::new();
+    let mut best = None;
+
+    queue.push(Work {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    });
+
+    while let Some(Work {
+        priority,
+        cost,
+        state,
+    }) = queue.pop()
+    {
+        // Finished if all remaining work is worse than the best goal
+        if best.is_some_and(|best| priority > best) {
+            break;
+        }
+
+        // Skip if a better route to this state has been found since it was queued
+        if result.cost_to(&state).is_some_and(|best| best < cost) {
+            continue;
+        }
+
+        // Reached a goal?
+        if is_goal(&state) {
+            best.get_or_insert(cost);
+            result.goals.push(state);
+
+            if !all {
+                break;
+            }
+
+            continue;
+        }
+
+        // Step to each neighbour
+        for (next, step) in neighbours(&state) {
+            let cost = cost + step;
+
+            if result.relax(&state, &next, cost, all) {
+                queue.push(Work {
+                    priority: cost + heuristic(&next),
+                    cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    result
+}
+
+/// Priority queue entry, ordered as a min-heap on priority then preferring higher cost
+struct Work<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Work<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Work<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Work<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Work<S, C> {}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,118 @@
+use super::*;
+
+use crate::{geom::Coord, grid::Grid};
+
+fn c(x: usize, y: usize) -> Coord<usize> {
+    Coord::new(x, y)
+}
+
+const MAZE: &str = "\
+.....
+.###.
+.#...
+.#.#.
+...#.
+";
+
+fn open(grid: &Grid<bool>, pos: Coord<usize>) -> impl Iterator<Item = Coord<usize>> + '_ {
+    grid.neighbours4(pos).filter(|&p| grid[p])
+}
+
+#[test]
+fn test_bfs() {
+    let grid = Grid::parse(MAZE, |c| c == '.').unwrap();
+
+    let result = bfs(c(0, 0), |&p| open(&grid, p), |&p| p == c(4, 4));
+    assert_eq!(result.cost(), Some(8));
+    assert_eq!(result.path().unwrap().len(), 9);
+    assert_eq!(result.cost_to(&c(2, 4)), Some(6));
+
+    // Unreachable goal
+    let result = bfs(c(0, 0), |&p| open(&grid, p), |&p| p == c(3, 4));
+    assert_eq!(result.goal(), None);
+    assert_eq!(result.cost(), None);
+    assert_eq!(result.costs().len(), 18);
+
+    // Start is the goal
+    let result = bfs(c(0, 0), |&p| open(&grid, p), |&p| p == c(0, 0));
+    assert_eq!(result.cost(), Some(0));
+    assert_eq!(result.path(), Some(vec![c(0, 0)]));
+}
+
+#[test]
+fn test_bfs_all() {
+    let grid = Grid::parse(MAZE, |c| c == '.').unwrap();
+
+    // Two routes around the walls to the centre
+    let result = bfs_all(c(0, 0), |&p| open(&grid, p), |&p| p == c(2, 2));
+    assert_eq!(result.cost(), Some(8));
+    assert_eq!(result.all_paths().len(), 2);
+
+    let result = bfs_all(c(0, 2), |&p| open(&grid, p), |&p| p == c(4, 2));
+    assert_eq!(result.cost(), Some(8));
+    assert_eq!(result.all_paths().len(), 2);
+    assert_eq!(result.optimal_states().len(), 16);
+
+    // Open grid has 6 shortest paths across a 3x3 square
+    let grid = Grid::new(3, 3, true);
+    let result = bfs_all(c(0, 0), |&p| open(&grid, p), |&p| p == c(2, 2));
+    assert_eq!(result.cost(), Some(4));
+    assert_eq!(result.all_paths().len(), 6);
+    assert_eq!(result.optimal_states().len(), 9);
+
+    for path in result.all_paths() {
+        assert_eq!(path.first(), Some(&c(0, 0)));
+        assert_eq!(path.last(), Some(&c(2, 2)));
+    }
+}
+
+#[test]
+fn test_dijkstra() {
+    // Direct edge is more expensive than the long way round
+    let edges: [&[(u8, u32)]; 4] = [&[(1, 1), (3, 10)], &[(2, 2)], &[(3, 3)], &[]];
+
+    let result = dijkstra(0u8, |&n| edges[n as usize].iter().copied(), |&n| n == 3);
+    assert_eq!(result.cost(), Some(6));
+    assert_eq!(result.path(), Some(vec![0, 1, 2, 3]));
+
+    // Tie between two routes
+    let edges: [&[(u8, u32)]; 4] = [&[(1, 1), (2, 2)], &[(3, 2)], &[(3, 1)], &[]];
+
+    let result = dijkstra(0u8, |&n| edges[n as usize].iter().copied(), |&n| n == 3);
+    assert_eq!(result.cost(), Some(3));
+    assert_eq!(result.predecessors(&3).len(), 1);
+
+    let result = dijkstra_all(0u8, |&n| edges[n as usize].iter().copied(), |&n| n == 3);
+    assert_eq!(result.cost(), Some(3));
+    assert_eq!(result.predecessors(&3).len(), 2);
+    assert_eq!(result.all_paths().len(), 2);
+    assert_eq!(result.optimal_states().len(), 4);
+}
+
+#[test]
+fn test_dijkstra_all_goals() {
+    // Goal states 2 and 3 are reached at the same cost, 4 costs more
+    let edges: [&[(u8, u32)]; 5] = [&[(1, 1), (4, 3)], &[(2, 1), (3, 1)], &[], &[], &[]];
+
+    let result = dijkstra_all(0u8, |&n| edges[n as usize].iter().copied(), |&n| n >= 2);
+    assert_eq!(result.cost(), Some(2));
+
+    let mut goals = result.goals().to_vec();
+    goals.sort();
+    assert_eq!(goals, vec![2, 3]);
+}
+
+#[test]
+fn test_astar() {
+    let grid = Grid::parse(MAZE, |c| c == '.').unwrap();
+    let end = c(4, 4);
+
+    let result = astar(
+        c(0, 0),
+        |&p| open(&grid, p).map(|p| (p, 1)),
+        |p| p.manhattan(&end),
+        |&p| p == end,
+    );
+    assert_eq!(result.cost(), Some(8));
+    assert_eq!(result.path().unwrap().last(), Some(&end));
+}
@@ -0,0 +1,218 @@
+use std::{any::Any, error::Error, fmt};
+
+use crate::{
+    input::{parse_input, InputSource},
+    params::{for_day, load_config, parse_day_override, unknown, Override},
+};
+
+/// A solution to a day's puzzle
+pub trait Solution {
+    /// Day number of the puzzle
+    const DAY: usize;
+
+    /// Parsed puzzle input
+    type Input;
+
+    /// True if parsing also does solving work shared by both parts, so parse timings
+    /// include some of the solve time
+    const SHARED_PARSE: bool = false;
+
+    /// Parses the puzzle input
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>>;
+
+    /// Solves part 1 of the puzzle
+    fn part1(&self, input: &Self::Input) -> Answer;
+
+    /// Solves part 2 of the puzzle. Returns None if the puzzle has no part 2
+    fn part2(&self, input: &Self::Input) -> Option<Answer>;
+
+    /// Returns the name and value of each puzzle parameter
+    fn params(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    /// Sets a puzzle parameter from its text value
+    fn set_param(&mut self, name: &str, _value: &str) -> Result<(), Box<dyn Error>> {
+        Err(unknown(name))
+    }
+}
+
+/// The answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// Integer answer
+    Int(u64),
+    /// Single line string answer
+    Str(String),
+    /// Multi-line answer, such as text drawn on a grid
+    Lines(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Str(s) => f.write_str(s),
+            Answer::Lines(lines) => f.write_str(&lines.join("\n")),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Int(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(value: Vec<String>) -> Self {
+        Answer::Lines(value)
+    }
+}
+
+/// Object safe view of a solution used to build tables of days
+pub trait DaySolution {
+    /// Returns the day number of the puzzle
+    fn day(&self) -> usize;
+
+    /// Returns true if parsing also does solving work shared by both parts
+    fn shared_parse(&self) -> bool;
+
+    /// Parses the input to a type erased value for use with `solve_part`
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>, Box<dyn Error>>;
+
+    /// Solves a part (1 or 2) from input returned by `parse_any`. Returns None if there is no such part
+    fn solve_part(&self, part: usize, input: &dyn Any) -> Option<Answer>;
+
+    /// Returns the name and value of each puzzle parameter
+    fn params(&self) -> Vec<(&'static str, String)>;
+
+    /// Returns a copy of the solution with parameters overridden in order
+    fn with_params(&self, overrides: &[Override]) -> Result<Box<dyn DaySolution>, Box<dyn Error>>;
+
+    /// Parses the input and returns the answer for each part
+    fn solve(&self, input: &str) -> Result<Vec<Answer>, Box<dyn Error>> {
+        let input = self.parse_any(input)?;
+
+        Ok((1..=2)
+            .filter_map(|part| self.solve_part(part, input.as_ref()))
+            .collect())
+    }
+}
+
+impl<S: Solution + Clone + 'static> DaySolution for S
+where
+    S::Input: 'static,
+{
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn shared_parse(&self) -> bool {
+        S::SHARED_PARSE
+    }
+
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>, Box<dyn Error>> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn solve_part(&self, part: usize, input: &dyn Any) -> Option<Answer> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input type does not match solution");
+
+        match part {
+            1 => Some(self.part1(input)),
+            2 => self.part2(input),
+            _ => None,
+        }
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        Solution::params(self)
+    }
+
+    fn with_params(&self, overrides: &[Override]) -> Result<Box<dyn DaySolution>, Box<dyn Error>> {
+        let mut solution = self.clone();
+
+        for (name, value) in overrides {
+            solution.set_param(name, value)?;
+        }
+
+        Ok(Box::new(solution))
+    }
+}
+
+/// Loads the input for a day, solves each part and prints the answers.
+/// An input file can be given as a command line argument (`-` for stdin). Puzzle
+/// parameters are set with `--param name=value` or loaded with `--config <file>`, and
+/// `--params` lists them instead of solving
+pub fn run(solution: &dyn DaySolution) -> Result<(), Box<dyn Error>> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let mut source = InputSource::Day(solution.day());
+    let mut config = Vec::new();
+    let mut overrides = Vec::new();
+    let mut list = false;
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("No value given for {arg}"));
+
+        match arg.as_str() {
+            "-p" | "--param" => overrides.push(parse_day_override(value()?)?),
+            "--config" => config.extend(load_config(value()?, solution.day())?),
+            "--params" => list = true,
+            opt if opt.starts_with("--") => Err(format!("Unknown option '{opt}'"))?,
+            file => source = InputSource::from_arg(file),
+        }
+    }
+
+    // Parameters on the command line override the config file
+    config.extend(for_day(&overrides, solution.day()));
+
+    let solution = solution.with_params(&config)?;
+
+    if list {
+        for (name, value) in solution.params() {
+            println!("{name} = {value}");
+        }
+
+        return Ok(());
+    }
+
+    run_source(solution.as_ref(), source)
+}
+
+/// Loads input from a source, solves each part and prints the answers
+pub fn run_source(solution: &dyn DaySolution, source: InputSource) -> Result<(), Box<dyn Error>> {
+    let answers = parse_input(source, |input| solution.solve(input))??;
+
+    for (part, answer) in answers.iter().enumerate() {
+        match answer {
+            Answer::Lines(_) => println!("Part {}:\n{answer}", part + 1),
+            _ => println!("Part {}: {answer}", part + 1),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,246 @@
+use std::{error::Error, path::Path, str::FromStr};
+
+use crate::gif::{scaled_size, CaptionBar, Format, Gif};
+
+/// Command line usage for the options
+pub const USAGE: &str = "\
+Options:
+  -o, --output <path>   output file instead of the default under vis/
+  --format <format>     gif, apng, png-dir, y4m or term. Guessed from the
+                        output file extension if not given
+  --term                watch in the terminal, the same as --format term
+  --scale <n>           pixels per cell
+  --frame-skip <n>      draw every nth step
+  --delay <n>           frame delay in hundredths of a second
+  --max-frames <n>      stop drawing after a number of frames
+  --part <n>            only render part n";
+
+/// Command line options shared by the visualisations. Settings not given are left to each
+/// visualisation's defaults
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Output path
+    pub output: Option<String>,
+    /// Output format
+    pub format: Option<Format>,
+    /// Pixels per cell
+    pub scale: Option<u16>,
+    /// Number of steps per frame drawn
+    pub frame_skip: Option<usize>,
+    /// Frame delay in hundredths of a second
+    pub delay: Option<u16>,
+    /// Maximum number of frames to output
+    pub max_frames: Option<usize>,
+    /// Part to render
+    pub part: Option<usize>,
+}
+
+impl Options {
+    /// Parses the options from the command line, printing the usage if they are invalid
+    /// or help is asked for
+    pub fn from_args() -> Result<Self, Box<dyn Error>> {
+        let args = std::env::args().skip(1).collect::<Vec<_>>();
+        let args = args.iter().map(|a| a.as_str()).collect::<Vec<_>>();
+
+        if args.iter().any(|a| *a == "-h" || *a == "--help") {
+            eprintln!("{USAGE}");
+            std::process::exit(0);
+        }
+
+        Self::parse(&args).inspect_err(|_| eprintln!("{USAGE}"))
+    }
+
+    /// Parses options from command line arguments
+    pub fn parse(args: &[&str]) -> Result<Self, Box<dyn Error>> {
+        let mut options = Self::default();
+
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("No value given for {arg}"));
+
+            match *arg {
+                "-o" | "--output" => options.output = Some(value()?.to_string()),
+                "--format" => options.format = Some(value()?.parse()?),
+                "--term" => options.format = Some(Format::Term),
+                "--scale" => options.scale = Some(positive(arg, value()?)?),
+                "--frame-skip" => options.frame_skip = Some(positive(arg, value()?)?),
+                "--delay" => {
+                    options.delay = Some(
+                        value()?
+                            .parse()
+                            .map_err(|_| format!("{arg} must be a number"))?,
+                    )
+                }
+                "--max-frames" => options.max_frames = Some(positive(arg, value()?)?),
+                "--part" => {
+                    options.part = match value()?.parse() {
+                        Ok(part @ 1..=2) => Some(part),
+                        _ => Err("Part must be 1 or 2")?,
+                    }
+                }
+                _ => Err(format!("Unknown option '{arg}'"))?,
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Checks no options are given besides the output path, for visualisations which don't
+    /// draw frames
+    pub fn output_only(self) -> Result<Self, Box<dyn Error>> {
+        let output_only = Self {
+            output: self.output.clone(),
+            ..Self::default()
+        };
+
+        if self != output_only {
+            Err("Only -o/--output applies to this visualisation")?
+        }
+
+        Ok(self)
+    }
+
+    /// Returns true if a part should be rendered
+    pub fn wants_part(&self, part: usize) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+
+    /// Returns the options for rendering one of several parts. An output path given for
+    /// all parts has the part number added so the parts don't overwrite each other. The
+    /// format is worked out from the path before it is changed
+    pub fn for_part(&self, part: usize) -> Self {
+        match (&self.output, self.part) {
+            (Some(output), None) => {
+                // Keep the trailing separator of a frames directory
+                let (output, dir) = match output.strip_suffix('/') {
+                    Some(output) => (output, "/"),
+                    None => (output.as_str(), ""),
+                };
+
+                let path = Path::new(output);
+
+                let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+
+                let name = match path.extension() {
+                    Some(ext) => format!("{stem}-{part}.{}", ext.to_string_lossy()),
+                    None => format!("{stem}-{part}"),
+                };
+
+                let output = path.with_file_name(name).to_string_lossy().into_owned() + dir;
+
+                Self {
+                    output: Some(output),
+                    format: Some(self.format()),
+                    ..self.clone()
+                }
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns the output path, or a default
+    pub fn output(&self, default: &str) -> String {
+        self.output.clone().unwrap_or_else(|| default.to_string())
+    }
+
+    /// Returns the output format, guessing from the output path if not given
+    pub fn format(&self) -> Format {
+        self.format
+            .or_else(|| self.output.as_deref().and_then(Format::from_path))
+            .unwrap_or(Format::Gif)
+    }
+
+    /// Returns the scale, or a default
+    pub fn scale(&self, default: u16) -> u16 {
+        self.scale.unwrap_or(default)
+    }
+
+    /// Returns the frame skip, or a default
+    pub fn frame_skip(&self, default: usize) -> usize {
+        self.frame_skip.unwrap_or(default)
+    }
+
+    /// Returns the frame delay, or a default
+    pub fn delay(&self, default: u16) -> u16 {
+        self.delay.unwrap_or(default)
+    }
+
+    /// Creates an animation with the chosen output, format, scale and frame limit. The
+    /// default path's extension is changed to suit the format
+    pub fn gif(
+        &self,
+        default: &str,
+        palette: &[[u8; 3]],
+        width: u16,
+        height: u16,
+        scale: u16,
+    ) -> Result<Gif, Box<dyn Error>> {
+        let scale = self.scale(scale);
+
+        let mut gif = Gif::with_format(
+            &self.animation_path(default),
+            self.format(),
+            palette,
+            width,
+            height,
+            scale,
+            scale,
+        )?;
+
+        gif.set_max_frames(self.max_frames);
+
+        Ok(gif)
+    }
+
+    /// Creates an animation with a caption bar with the chosen output, format, scale and
+    /// frame limit
+    pub fn gif_with_caption(
+        &self,
+        default: &str,
+        palette: &[[u8; 3]],
+        width: u16,
+        height: u16,
+        scale: u16,
+        caption: CaptionBar,
+    ) -> Result<Gif, Box<dyn Error>> {
+        let scale = self.scale(scale);
+
+        let (gif_width, gif_height) = scaled_size(width, height, scale, scale, caption.height())?;
+        let sink = self.format().sink(
+            &self.animation_path(default),
+            palette,
+            gif_width,
+            gif_height,
+        )?;
+
+        let mut gif = Gif::with_sink_caption(sink, width, height, scale, scale, caption);
+
+        gif.set_max_frames(self.max_frames);
+
+        Ok(gif)
+    }
+
+    /// Returns the output path for an animation
+    fn animation_path(&self, default: &str) -> String {
+        match (&self.output, self.format) {
+            (Some(output), _) => output.clone(),
+            (None, Some(format)) => format.path_for(default),
+            (None, None) => default.to_string(),
+        }
+    }
+}
+
+/// Parses a positive number for an option
+fn positive<T>(arg: &str, value: &str) -> Result<T, Box<dyn Error>>
+where
+    T: FromStr + Default + PartialOrd,
+{
+    match value.parse::<T>() {
+        Ok(n) if n > T::default() => Ok(n),
+        _ => Err(format!("{arg} must be a positive integer"))?,
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,113 @@
+use super::*;
+
+#[test]
+fn test_parse() {
+    let options = Options::parse(&[
+        "-o",
+        "out.png",
+        "--scale",
+        "3",
+        "--frame-skip",
+        "10",
+        "--delay",
+        "0",
+        "--max-frames",
+        "50",
+        "--part",
+        "2",
+    ])
+    .unwrap();
+
+    assert_eq!(
+        options,
+        Options {
+            output: Some("out.png".to_string()),
+            format: None,
+            scale: Some(3),
+            frame_skip: Some(10),
+            delay: Some(0),
+            max_frames: Some(50),
+            part: Some(2),
+        }
+    );
+
+    assert_eq!(options.format(), Format::Apng);
+    assert_eq!(options.scale(5), 3);
+    assert_eq!(options.frame_skip(4), 10);
+    assert!(!options.wants_part(1));
+    assert!(options.wants_part(2));
+
+    assert_eq!(Options::parse(&[]).unwrap(), Options::default());
+    assert_eq!(Options::default().format(), Format::Gif);
+    assert_eq!(
+        Options::parse(&["--term"]).unwrap().format,
+        Some(Format::Term)
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!(Options::parse(&["--scale"]).is_err());
+    assert!(Options::parse(&["--scale", "0"]).is_err());
+    assert!(Options::parse(&["--frame-skip", "x"]).is_err());
+    assert!(Options::parse(&["--part", "3"]).is_err());
+    assert!(Options::parse(&["--format", "bmp"]).is_err());
+    assert!(Options::parse(&["--fast"]).is_err());
+}
+
+#[test]
+fn test_output_only() {
+    let options = Options::parse(&["-o", "graph.dot"]).unwrap();
+
+    assert_eq!(
+        options.output_only().unwrap().output("vis/day23.dot"),
+        "graph.dot"
+    );
+    assert!(Options::parse(&[]).unwrap().output_only().is_ok());
+
+    let options = Options::parse(&["-o", "graph.dot", "--scale", "2"]).unwrap();
+
+    assert!(options.output_only().is_err());
+    assert!(Options::parse(&["--term"]).unwrap().output_only().is_err());
+}
+
+#[test]
+fn test_paths() {
+    let options = Options::parse(&["--format", "y4m"]).unwrap();
+
+    assert_eq!(options.animation_path("vis/day04-1.gif"), "vis/day04-1.y4m");
+    assert_eq!(options.output("vis/day23.dot"), "vis/day23.dot");
+
+    let options = Options::parse(&["-o", "out/xmas.gif"]).unwrap();
+
+    assert_eq!(
+        options.for_part(2).animation_path("vis/day04-2.gif"),
+        "out/xmas-2.gif"
+    );
+
+    // A single part keeps the output path
+    let options = Options::parse(&["-o", "xmas.gif", "--part", "1"]).unwrap();
+
+    assert_eq!(options.for_part(1).output, Some("xmas.gif".to_string()));
+
+    // A frames directory keeps its trailing separator and format
+    let options = Options::parse(&["-o", "out/frames/"]).unwrap().for_part(2);
+
+    assert_eq!(options.output, Some("out/frames-2/".to_string()));
+    assert_eq!(options.format(), Format::PngDir);
+
+    let options = Options::parse(&["-o", "frames", "--format", "png-dir"]).unwrap();
+
+    assert_eq!(options.for_part(1).output, Some("frames-1".to_string()));
+    assert_eq!(options.for_part(1).format(), Format::PngDir);
+}
+
+#[test]
+fn test_scale_overflow() {
+    let options = Options::parse(&["--scale", "1000", "-o", "/dev/null"]).unwrap();
+
+    assert!(options.gif("x.gif", &[[0; 3]], 100, 100, 1).is_err());
+    assert!(options
+        .gif_with_caption("x.gif", &[[0; 3]], 100, 1, 1, CaptionBar::new(1, 0, 1))
+        .is_err());
+}
@@ -0,0 +1,79 @@
+use std::error::Error;
+
+use aoc::{
+    input::{InputSource, parse_input_vec},
+    solution::{Answer, Solution},
+};
+
+/// Day 1 solution
+#[derive(Clone)]
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: usize = 1;
+
+    type Input = (Vec<u64>, Vec<u64>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let input = parse_input_vec(InputSource::Str(input), input_transform)?;
+
+        Ok(split_input(&input))
+    }
+
+    fn part1(&self, (v1, v2): &Self::Input) -> Answer {
+        part1(v1, v2).into()
+    }
+
+    fn part2(&self, (v1, v2): &Self::Input) -> Option<Answer> {
+        Some(part2(v1, v2).into())
+    }
+}
+
+/// Sums the distances between the paired sorted location IDs
+pub fn part1(v1: &[u64], v2: &[u64]) -> u64 {
+    v1.iter().zip(v2).map(|(n1, n2)| n1.abs_diff(*n2)).sum()
+}
+
+/// Sums each left list ID multiplied by its count in the right list
+pub fn part2(v1: &[u64], v2: &[u64]) -> u64 {
+    v1.iter()
+        .map(|n1| {
+            let p1 = v2.partition_point(|n2| n2 < n1);
+            v2[p1..].partition_point(|n2| n2 <= n1) as u64 * n1
+        })
+        .sum()
+}
+
+// Input parsing
+
+pub type InputEnt = Vec<u64>;
+
+/// Parses a line of location IDs
+pub fn input_transform(line: &str) -> InputEnt {
+    line.split_ascii_whitespace()
+        .map(|n| {
+            n.parse::<u64>()
+                .unwrap_or_else(|_| panic!("{n} is not an integer"))
+        })
+        .collect()
+}
+
+/// Splits the parsed lines into the sorted left and right lists
+pub fn split_input(input: &[Vec<u64>]) -> (Vec<u64>, Vec<u64>) {
+    let (mut v1, mut v2) = input
+        .iter()
+        .fold((Vec::new(), Vec::new()), |(mut v1, mut v2), v| {
+            v1.push(v[0]);
+            v2.push(v[1]);
+
+            (v1, v2)
+        });
+
+    v1.sort();
+    v2.sort();
+
+    (v1, v2)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,73 @@
+use std::error::Error;
+
+use aoc::{
+    input::{InputSource, parse_input_vec},
+    solution::{Answer, Solution},
+};
+
+/// Day 2 solution
+#[derive(Clone)]
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: usize = 2;
+
+    type Input = Vec<InputEnt>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_input_vec(InputSource::Str(input), input_transform)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
+
+/// Counts the safe reports
+pub fn part1(input: &[InputEnt]) -> usize {
+    input.iter().filter(|nums| is_safe(nums)).count()
+}
+
+/// Counts the reports which are safe with at most one level removed
+pub fn part2(input: &[InputEnt]) -> usize {
+    input.iter().filter(|nums| is_tolerable(nums)).count()
+}
+
+fn is_safe(nums: &[i8]) -> bool {
+    // Check strictly monotonic increasing or decreasing with max 3 gap
+    nums.is_sorted_by(|a, b| (1..=3).contains(&(b - a)))
+        || nums.is_sorted_by(|a, b| (1..=3).contains(&(a - b)))
+}
+
+fn is_tolerable(nums: &[i8]) -> bool {
+    (0..nums.len()).any(|i| {
+        is_safe(
+            &(nums
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, n)| if idx != i { Some(*n) } else { None })
+                .collect::<Vec<_>>()),
+        )
+    })
+}
+
+// Input parsing
+
+pub type InputEnt = Vec<i8>;
+
+/// Parses a report line of levels
+pub fn input_transform(line: &str) -> InputEnt {
+    line.split_ascii_whitespace()
+        .map(|s| {
+            s.parse::<i8>()
+                .unwrap_or_else(|_| panic!("{s} is not an integer"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
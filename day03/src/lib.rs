@@ -0,0 +1,74 @@
+use regex::Regex;
+use std::error::Error;
+
+use aoc::solution::{Answer, Solution};
+
+/// Day 3 solution
+#[derive(Clone)]
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: usize = 3;
+
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(input.into())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
+
+/// Sums the results of the mul instructions
+pub fn part1(input: &str) -> u64 {
+    let re = Regex::new("mul\\(([0-9]+),([0-9]+)\\)").expect("Failed to create regex");
+
+    re.captures_iter(input)
+        .map(|nums| nums.extract())
+        .map(|(_instr, [astr, bstr])| {
+            let a = astr.parse::<u64>().expect("a is not u64");
+            let b = bstr.parse::<u64>().expect("b is not u64");
+
+            a * b
+        })
+        .sum()
+}
+
+/// Sums the results of the mul instructions enabled by do() and don't()
+pub fn part2(input: &str) -> u64 {
+    let mut pos = 0;
+    let mut filtered = String::with_capacity(input.len());
+
+    loop {
+        match input[pos..].find("don't()") {
+            Some(p) => {
+                filtered.push_str(&input[pos..(pos + p)]);
+                pos += p;
+            }
+            None => {
+                filtered.push_str(&input[pos..]);
+                break;
+            }
+        }
+
+        match input[pos..].find("do()") {
+            Some(p) => {
+                pos += p + 4;
+            }
+            None => {
+                break;
+            }
+        }
+    }
+
+    part1(&filtered)
+}
+
+#[cfg(test)]
+mod tests;
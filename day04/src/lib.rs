@@ -0,0 +1,82 @@
+use std::error::Error;
+
+use aoc::{
+    geom::Dir8,
+    grid::Grid,
+    solution::{Answer, Solution},
+};
+
+/// Day 4 solution
+#[derive(Clone)]
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: usize = 4;
+
+    type Input = Grid<u8>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(Grid::parse_try(input, input_transform)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
+
+/// Counts the occurrences of XMAS in any direction
+pub fn part1(grid: &Grid<u8>) -> u64 {
+    // Loop each X on the board
+    grid.find_all(&0)
+        .map(|pos| {
+            // Search for MAS in all directions
+            Dir8::ALL
+                .into_iter()
+                .filter(|dir| {
+                    grid.ray(pos, dir.offset())
+                        .take(3)
+                        .map(|p| grid[p])
+                        .eq(1..4)
+                })
+                .count() as u64
+        })
+        .sum()
+}
+
+/// Counts the X shaped crosses of MAS
+pub fn part2(grid: &Grid<u8>) -> u64 {
+    // Function to check we have M and S or S and M in the board contents provided
+    let check = |a, b| matches!((a, b), (Some(1), Some(3)) | (Some(3), Some(1)));
+
+    // Loop each A on the board
+    grid.find_all(&2)
+        .filter(|&pos| {
+            // Contents of a diagonal neighbour, or None off the edge of the board
+            let diag = |dir: Dir8| grid.offset(pos, dir.offset()).map(|p| grid[p]);
+
+            // Call the check function with contents of the diagonals
+            check(diag(Dir8::NW), diag(Dir8::SE)) && check(diag(Dir8::NE), diag(Dir8::SW))
+        })
+        .count() as u64
+}
+
+// Input parsing
+
+/// Parses a grid letter
+pub fn input_transform(c: char) -> Result<u8, String> {
+    // Convert board chars to word letter index
+    match c {
+        'X' => Ok(0),
+        'M' => Ok(1),
+        'A' => Ok(2),
+        'S' => Ok(3),
+        _ => Err(format!("Invalid char {c}")),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -1,13 +1,21 @@
 use std::error::Error;
 
-use aoc::{gif::Gif, input::parse_input_vec};
+use aoc::{gif::Palette, input::parse_input_vec, vis::Options};
 
 fn main() -> Result<(), Box<dyn Error>> {
+    // Get options
+    let options = Options::from_args()?;
+
     // Get input
     let input = parse_input_vec(4, input_transform)?;
 
-    part1(&input)?;
-    part2(&input)?;
+    if options.wants_part(1) {
+        part1(&input, &options.for_part(1))?;
+    }
+
+    if options.wants_part(2) {
+        part2(&input, &options.for_part(2))?;
+    }
 
     Ok(())
 }
@@ -25,7 +33,7 @@ const DIRECTIONS: [(i8, i8); 8] = [
 
 const CELL_SIZE: usize = 7;
 
-fn part1(input: &[InputEnt]) -> Result<(), Box<dyn Error>> {
+fn part1(input: &[InputEnt], options: &Options) -> Result<(), Box<dyn Error>> {
     let mut hits = vec![vec![0u8; input[0].len()]; input.len()];
 
     let check_word = |mut x: isize, mut y: isize, dx: isize, dy: isize| -> bool {
@@ -81,10 +89,10 @@ fn part1(input: &[InputEnt]) -> Result<(), Box<dyn Error>> {
         }
     }
 
-    draw_hits(input, &hits, "vis/day04-1.gif")
+    draw_hits(input, &hits, options, "vis/day04-1.gif")
 }
 
-fn part2(input: &[InputEnt]) -> Result<(), Box<dyn Error>> {
+fn part2(input: &[InputEnt], options: &Options) -> Result<(), Box<dyn Error>> {
     let mut hits = vec![vec![0u8; input[0].len()]; input.len()];
 
     // Function to check we have M and S or S and M in the board contents provided
@@ -108,7 +116,7 @@ fn part2(input: &[InputEnt]) -> Result<(), Box<dyn Error>> {
         }
     }
 
-    draw_hits(input, &hits, "vis/day04-2.gif")
+    draw_hits(input, &hits, options, "vis/day04-2.gif")
 }
 
 const LETTERS: [[[u8; 5]; 5]; 4] = [
@@ -142,7 +150,12 @@ const LETTERS: [[[u8; 5]; 5]; 4] = [
     ],
 ];
 
-fn draw_hits(input: &[InputEnt], hits: &[Vec<u8>], file: &str) -> Result<(), Box<dyn Error>> {
+fn draw_hits(
+    input: &[InputEnt],
+    hits: &[Vec<u8>],
+    options: &Options,
+    file: &str,
+) -> Result<(), Box<dyn Error>> {
     // Calculate maximum hits
     let max_hits = hits
         .iter()
@@ -151,25 +164,27 @@ fn draw_hits(input: &[InputEnt], hits: &[Vec<u8>], file: &str) -> Result<(), Box
         .unwrap();
 
     // Build the palette
-    let mut palette = Vec::new();
+    let mut palette = Palette::new();
 
-    palette.push([0, 0, 0]);
+    let black = palette.add([0, 0, 0])?;
 
     // Background colours
-    for i in 1..=max_hits {
-        let c = 127 + (i * (128 / max_hits));
-        palette.push([c, c, c]);
-    }
+    let step = 128 / max_hits;
+    let background = palette.ramp(
+        [127 + step; 3],
+        [127 + (max_hits * step); 3],
+        max_hits as usize,
+    )?;
 
     // No hit text colour
-    palette.push([255, 0, 0]);
+    let red = palette.add([255, 0, 0])?;
 
     // Calculate dimensions
     let width = (input[0].len() * CELL_SIZE) as u16;
     let height = (input.len() * CELL_SIZE) as u16;
 
     // Create the gif
-    let mut gif = Gif::new(file, &palette, width, height, 1, 1)?;
+    let mut gif = options.gif(file, palette.colours(), width, height, 1)?;
 
     // Create the frame
     let mut frame_data = gif.empty_frame();
@@ -185,20 +200,26 @@ fn draw_hits(input: &[InputEnt], hits: &[Vec<u8>], file: &str) -> Result<(), Box
             let gx = hx * CELL_SIZE;
 
             // Fill background
+            let background = if *hc == 0 {
+                black
+            } else {
+                background.get(*hc as usize - 1)
+            };
+
             for y in 0..CELL_SIZE {
                 for x in 0..CELL_SIZE {
-                    frame_data[gy + y][gx + x] = *hc;
+                    frame_data[gy + y][gx + x] = background.into();
                 }
             }
 
             // Draw letter
             let letter = &LETTERS[input[hy][hx] as usize];
-            let text_colour = if *hc == 0 { palette.len() - 1 } else { 0 } as u8;
+            let text_colour = if *hc == 0 { red } else { black };
 
             for (ly, ll) in letter.iter().enumerate() {
                 for (lx, lc) in ll.iter().enumerate() {
                     if *lc == 1 {
-                        frame_data[gy + ly + 1][gx + lx + 1] = text_colour;
+                        frame_data[gy + ly + 1][gx + lx + 1] = text_colour.into();
                     }
                 }
             }
@@ -0,0 +1,119 @@
+use std::{cmp::Ordering, error::Error};
+
+use aoc::{
+    input::sections,
+    solution::{Answer, Solution},
+};
+use fxhash::FxHashSet;
+
+/// Day 5 solution
+#[derive(Clone)]
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: usize = 5;
+
+    type Input = (PageOrder, Vec<Vec<u8>>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_input_str(input)?)
+    }
+
+    fn part1(&self, (orders, prints): &Self::Input) -> Answer {
+        part1(orders, prints).into()
+    }
+
+    fn part2(&self, (orders, prints): &Self::Input) -> Option<Answer> {
+        Some(part2(orders, prints).into())
+    }
+}
+
+/// Sums the middle pages of the correctly ordered updates
+pub fn part1(orders: &PageOrder, prints: &[Vec<u8>]) -> u64 {
+    prints
+        .iter()
+        .filter_map(|print| match correct_order(print, orders) {
+            None => Some(print[print.len() / 2] as u64),
+            _ => None,
+        })
+        .sum()
+}
+
+/// Sums the middle pages of the incorrectly ordered updates once reordered
+pub fn part2(orders: &PageOrder, prints: &[Vec<u8>]) -> u64 {
+    prints
+        .iter()
+        .filter_map(|print| correct_order(print, orders).map(|order| order[order.len() / 2] as u64))
+        .sum()
+}
+
+fn correct_order(print: &[u8], orders: &PageOrder) -> Option<Vec<u8>> {
+    let mut sorted = print.to_vec();
+
+    sorted.sort_by(|a, b| {
+        if orders.contains(&[*a, *b]) {
+            Ordering::Less
+        } else if orders.contains(&[*b, *a]) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    });
+
+    if sorted != *print { Some(sorted) } else { None }
+}
+
+// Input parsing
+
+pub type PageOrder = FxHashSet<[u8; 2]>;
+
+/// Parses the page ordering rules and the updates
+pub fn parse_input_str(input: &str) -> Result<(PageOrder, Vec<Vec<u8>>), aoc::Error> {
+    let mut sections = sections(input);
+
+    let section = sections
+        .next()
+        .ok_or(aoc::Error::parse("Page order section not found"))?;
+
+    let orders = section
+        .numbered_lines()
+        .map(|(lineno, line)| {
+            let pages = parse_pages(line, '|').map_err(|e| e.at_line(lineno))?;
+
+            match pages[..] {
+                [a, b] => Ok([a, b]),
+                _ => Err(aoc::Error::parse("Expected two pages separated by '|'").at_line(lineno)),
+            }
+        })
+        .collect::<Result<_, _>>()?;
+
+    let section = sections
+        .next()
+        .ok_or(aoc::Error::parse("Print section not found"))?;
+
+    let prints = section
+        .numbered_lines()
+        .map(|(lineno, line)| parse_pages(line, ',').map_err(|e| e.at_line(lineno)))
+        .collect::<Result<_, _>>()?;
+
+    Ok((orders, prints))
+}
+
+fn parse_pages(line: &str, sep: char) -> Result<Vec<u8>, aoc::Error> {
+    let mut column = 1;
+
+    line.split(sep)
+        .map(|n| {
+            let page = n
+                .parse::<u8>()
+                .map_err(|e| aoc::Error::parse_at(column, format!("Invalid page '{n}': {e}")));
+
+            column += n.len() + 1;
+
+            page
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
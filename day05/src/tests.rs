@@ -33,7 +33,25 @@ const EXAMPLE1: &str = "\
 
 #[test]
 fn test1() {
-    let (orders, prints) = parse_input_str(EXAMPLE1);
+    let (orders, prints) = parse_input_str(EXAMPLE1).unwrap();
     assert_eq!(part1(&orders, &prints), 143);
     assert_eq!(part2(&orders, &prints), 123);
 }
+
+#[test]
+fn test_parse_error() {
+    let err = parse_input_str("47|53\n97|x3\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parse error at line 2, column 4: Invalid page 'x3': invalid digit found in string"
+    );
+
+    let err = parse_input_str("47|53\n97\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parse error at line 2: Expected two pages separated by '|'"
+    );
+
+    let err = parse_input_str("47|53\n").unwrap_err();
+    assert_eq!(err.to_string(), "Parse error: Print section not found");
+}
@@ -0,0 +1,215 @@
+use std::error::Error;
+
+use aoc::{
+    geom::Dir4,
+    grid::Grid,
+    observer::Observer,
+    solution::{Answer, Solution},
+};
+use fxhash::FxHashSet;
+
+/// Day 6 solution
+#[derive(Clone)]
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: usize = 6;
+
+    type Input = Board;
+
+    const SHARED_PARSE: bool = true;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let grid = Grid::parse_try(input, input_transform)?;
+
+        walk_board(grid)
+    }
+
+    fn part1(&self, board: &Self::Input) -> Answer {
+        part1(&board.path).into()
+    }
+
+    fn part2(&self, board: &Self::Input) -> Option<Answer> {
+        Some(part2(&mut board.grid.clone(), &board.path).into())
+    }
+}
+
+/// Board with the guard's path walked
+pub struct Board {
+    grid: Grid<Space>,
+    path: Vec<GuardState>,
+}
+
+/// Walks the guard's path on the parsed board
+pub fn walk_board(grid: Grid<Space>) -> Result<Board, Box<dyn Error>> {
+    // Get guard position
+    let guard_pos = guard_pos(&grid).ok_or("Unable to find the guard")?;
+
+    // Walk guard's path
+    let path = walk_path(&grid, guard_pos, &mut ());
+
+    Ok(Board { grid, path })
+}
+
+/// Counts the distinct positions visited by the guard
+pub fn part1(path: &[GuardState]) -> u64 {
+    // Return length of the path
+    let positions = path.iter().map(|s| s.pos).collect::<FxHashSet<Coord>>();
+
+    positions.len() as u64
+}
+
+/// Counts the obstruction positions which put the guard in a loop
+pub fn part2(grid: &mut Grid<Space>, path: &[GuardState]) -> u64 {
+    // Pointer to last state
+    let mut last_state = &path[0];
+
+    // Set up turn hashset
+    let mut turns = FxHashSet::default();
+
+    // Block each untried space on the path and check if a loop occurs
+    path.iter()
+        .skip(1)
+        .filter(|&state| {
+            let mut looped = false;
+            let pos = state.pos;
+
+            if grid[pos] == Space::Empty {
+                // Block the position
+                grid[pos] = Space::Blocked;
+
+                // Check if a loop occurs
+                looped = loop_check(grid, last_state.clone(), &mut turns);
+
+                // Mark as tried
+                grid[pos] = Space::Tried;
+            };
+
+            // Update last state pointer
+            last_state = state;
+
+            looped
+        })
+        .count() as u64
+}
+
+/// Guard position and direction
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct GuardState {
+    pub pos: Coord,
+    pub dir: Dir4,
+}
+
+impl GuardState {
+    /// Returns the next position in the current direction if it is on the board
+    fn next_pos(&self, grid: &Grid<Space>) -> Option<Coord> {
+        grid.offset(self.pos, self.dir.offset())
+    }
+}
+
+/// Events emitted while walking the guard's path
+pub enum Event {
+    /// The guard moved forward
+    Moved(GuardState),
+    /// The guard turned right at an obstruction
+    Turned(GuardState),
+}
+
+/// Walks the guard's path until it leaves the board, returning each state moved to
+pub fn walk_path<O>(grid: &Grid<Space>, guard_pos: Coord, observer: &mut O) -> Vec<GuardState>
+where
+    O: Observer<Event>,
+{
+    // Set up initial guard state
+    let mut guard_state = GuardState {
+        pos: guard_pos,
+        dir: Dir4::N,
+    };
+
+    // Set up path
+    let mut visited = Vec::new();
+    visited.push(guard_state.clone());
+
+    // Loop next guard positions
+    while let Some(next) = guard_state.next_pos(grid) {
+        if grid[next] == Space::Blocked {
+            // Blocked - turn right
+            guard_state.dir = guard_state.dir.rotate_right();
+
+            observer.event(Event::Turned(guard_state.clone()));
+        } else {
+            // Set new position
+            guard_state.pos = next;
+
+            // Record guard state
+            visited.push(guard_state.clone());
+
+            observer.event(Event::Moved(guard_state.clone()));
+        }
+    }
+
+    visited
+}
+
+fn loop_check(
+    grid: &Grid<Space>,
+    mut guard_state: GuardState,
+    turns: &mut FxHashSet<GuardState>,
+) -> bool {
+    // Clear turn hashset
+    turns.clear();
+
+    // Get next position
+    while let Some(next) = guard_state.next_pos(grid) {
+        // Blocked?
+        if grid[next] == Space::Blocked {
+            // Seen this turn before?
+            if turns.contains(&guard_state) {
+                // Yes - there is a loop
+                return true;
+            }
+
+            // No - add this turn
+            turns.insert(guard_state.clone());
+
+            // Turn right
+            guard_state.dir = guard_state.dir.rotate_right();
+        } else {
+            // No - update guard position
+            guard_state.pos = next;
+        }
+    }
+
+    false
+}
+
+/// Finds the guard's starting position
+pub fn guard_pos(grid: &Grid<Space>) -> Option<Coord> {
+    grid.find_tile(&Space::Guard)
+}
+
+pub type Coord = aoc::geom::Coord<usize>;
+
+/// Board space
+#[derive(PartialEq, Clone)]
+pub enum Space {
+    Blocked,
+    Empty,
+    Guard,
+    Tried,
+}
+
+// Input parsing
+
+/// Parses a board space
+pub fn input_transform(c: char) -> Result<Space, String> {
+    match c {
+        '.' => Ok(Space::Empty),
+        '#' => Ok(Space::Blocked),
+        '^' => Ok(Space::Guard),
+        _ => Err(format!("Invalid board char {c}")),
+    }
+}
+
+#[cfg(test)]
+mod tests;
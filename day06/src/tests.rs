@@ -1,5 +1,3 @@
-use aoc::input::parse_test_vec;
-
 use super::*;
 
 const EXAMPLE1: &str = "\
@@ -17,10 +15,16 @@ const EXAMPLE1: &str = "\
 
 #[test]
 fn test1() {
-    let mut input = parse_test_vec(EXAMPLE1, input_transform).unwrap();
+    let grid = Grid::parse_try(EXAMPLE1, input_transform).unwrap();
+
+    let mut board = walk_board(grid).unwrap();
 
-    let (p1, p2) = run_parts(&mut input);
+    assert_eq!(part1(&board.path), 41);
+    assert_eq!(part2(&mut board.grid, &board.path), 6);
+}
 
-    assert_eq!(p1, 41);
-    assert_eq!(p2, 6);
+#[test]
+fn test_parse_errors() {
+    assert!(Grid::parse_try("..x\n", input_transform).is_err());
+    assert!(walk_board(Grid::parse_try("...\n", input_transform).unwrap()).is_err());
 }
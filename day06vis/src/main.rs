@@ -1,6 +1,7 @@
 use std::error::Error;
 
-use aoc::{gif::Gif, input::parse_input_vec};
+use aoc::{geom::Dir4, gif::Gif, grid::Grid, vis::Options};
+use day06::{Event, GuardState, Space, guard_pos, input_transform, walk_path};
 
 const CELLSIZE: usize = 7;
 
@@ -64,16 +65,21 @@ const BLOCK: [[u8; CELLSIZE]; CELLSIZE] = [
 ];
 
 fn main() -> Result<(), Box<dyn Error>> {
+    // Get options
+    let options = Options::from_args()?;
+    let frame_skip = options.frame_skip(1);
+    let delay = options.delay(2);
+
     // Get input
-    let input = parse_input_vec(6, input_transform)?;
+    let board = Grid::from_input_try(6, input_transform)?;
 
-    let mut board = input.clone();
+    let dim = board.size();
 
-    let boardx = board[0].len();
-    let boardy = board.len();
+    // Get guard position and count the start as a visit
+    let guard = guard_pos(&board).ok_or("Unable to find the guard")?;
 
-    let (mut gx, mut gy) = guard_pos(&input);
-    board[gy][gx] = Space::Guard(Dir::N, 1);
+    let mut visits = Grid::new(dim.x, dim.y, 0u8);
+    visits[guard] = 1;
 
     let mut palette = vec![[0, 0, 0], [64, 64, 255], [255, 128, 255], [0, 0, 0]];
 
@@ -84,45 +90,59 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     palette.push([255, 0, 0]);
 
-    let mut gif = Gif::new(
+    let mut gif = options.gif(
         "vis/day06-1.gif",
         &palette,
-        (boardx * CELLSIZE) as u16,
-        (boardy * CELLSIZE) as u16,
-        1,
+        (dim.x * CELLSIZE) as u16,
+        (dim.y * CELLSIZE) as u16,
         1,
     )?;
 
-    loop {
-        draw_frame(&mut gif, &board)?;
+    let start = GuardState {
+        pos: guard,
+        dir: Dir4::N,
+    };
 
-        let g = board[gy][gx].clone();
+    draw_frame(&mut gif, &board, &visits, &start, delay)?;
 
-        if let Space::Guard(dir, gvisited) = g {
-            if let Some((nx, ny)) = dir.next_pos(gx, gy, boardx, boardy) {
-                match board[ny][nx] {
-                    Space::Blocked => board[gy][gx] = Space::Guard(dir.rotate(), gvisited),
-                    Space::Empty(evisited) => {
-                        board[gy][gx] = Space::Empty(gvisited);
-                        (gx, gy) = (nx, ny);
-                        board[gy][gx] = Space::Guard(dir.clone(), evisited + 1);
-                    }
-                    _ => unreachable!(),
-                }
-            } else {
-                break;
+    // Draw a frame for each step the guard takes
+    let mut result = Ok(());
+    let mut steps = 0;
+
+    walk_path(&board, guard, &mut |event| {
+        if result.is_err() {
+            return;
+        }
+
+        let state = match event {
+            Event::Moved(state) => {
+                visits[state.pos] += 1;
+                state
             }
-        } else {
-            panic!("guard not valid")
+            Event::Turned(state) => state,
+        };
+
+        steps += 1;
+
+        if steps % frame_skip == 0 {
+            result = draw_frame(&mut gif, &board, &visits, &state, delay);
         }
-    }
+    });
+
+    result?;
 
     gif.delay(500)?;
 
     Ok(())
 }
 
-fn draw_frame(gif: &mut Gif, board: &Vec<BoardLine>) -> Result<(), Box<dyn Error>> {
+fn draw_frame(
+    gif: &mut Gif,
+    board: &Grid<Space>,
+    visits: &Grid<u8>,
+    guard: &GuardState,
+    delay: u16,
+) -> Result<(), Box<dyn Error>> {
     let mut frame = gif.empty_frame();
 
     let draw_masked =
@@ -136,118 +156,19 @@ fn draw_frame(gif: &mut Gif, board: &Vec<BoardLine>) -> Result<(), Box<dyn Error
             }
         };
 
-    let mut gy = 0;
-
-    for l in board {
-        let mut gx = 0;
-
-        for c in l {
-            match c {
-                Space::Blocked => draw_masked(&mut frame, gx, gy, 1, &BLOCK),
-                Space::Empty(visited) => draw_masked(&mut frame, gx, gy, 3 + visited, &VISITED),
-                Space::Guard(dir, _) => {
-                    let gpic = &GUARD[match dir {
-                        Dir::N => 0,
-                        Dir::E => 1,
-                        Dir::S => 2,
-                        Dir::W => 3,
-                    }];
-
-                    draw_masked(&mut frame, gx, gy, 2, gpic);
-                }
-            }
-
-            gx += CELLSIZE;
-        }
-
-        gy += CELLSIZE;
-    }
-
-    gif.draw_frame(frame, 2)
-}
-
-fn guard_pos(input: &[BoardLine]) -> (usize, usize) {
-    input
-        .iter()
-        .enumerate()
-        .find_map(|(y, l)| {
-            l.iter()
-                .enumerate()
-                .find_map(|(x, c)| {
-                    if matches!(*c, Space::Guard(_, _)) {
-                        Some(x)
-                    } else {
-                        None
-                    }
-                })
-                .map(|x| (x, y))
-        })
-        .expect("Unable to find the guard")
-}
-
-#[derive(Clone, PartialEq)]
-enum Space {
-    Blocked,
-    Empty(u8),
-    Guard(Dir, u8),
-}
-
-type BoardLine = Vec<Space>;
-
-#[derive(PartialEq, Eq, Hash, Clone)]
-enum Dir {
-    N,
-    E,
-    S,
-    W,
-}
-
-impl Dir {
-    fn next_pos(&self, gx: usize, gy: usize, xdim: usize, ydim: usize) -> Option<(usize, usize)> {
-        let (dx, dy) = match self {
-            Dir::N => (0, -1),
-            Dir::E => (1, 0),
-            Dir::S => (0, 1),
-            Dir::W => (-1, 0),
-        };
-
-        let move_dir = |p, d, max| match d {
-            -1 => {
-                if p == 0 {
-                    None
-                } else {
-                    Some(p - 1)
-                }
-            }
-            1 => {
-                let p = p + 1;
-                if p == max { None } else { Some(p) }
-            }
-            _ => Some(p),
-        };
+    for (pos, c) in board.iter() {
+        let (gx, gy) = (pos.x * CELLSIZE, pos.y * CELLSIZE);
 
-        Some((move_dir(gx, dx, xdim)?, move_dir(gy, dy, ydim)?))
-    }
+        if guard.pos == pos {
+            let gpic = &GUARD[guard.dir as usize];
 
-    fn rotate(&self) -> Dir {
-        match self {
-            Dir::N => Dir::E,
-            Dir::E => Dir::S,
-            Dir::S => Dir::W,
-            Dir::W => Dir::N,
+            draw_masked(&mut frame, gx, gy, 2, gpic);
+        } else if *c == Space::Blocked {
+            draw_masked(&mut frame, gx, gy, 1, &BLOCK);
+        } else {
+            draw_masked(&mut frame, gx, gy, 3 + visits[pos], &VISITED);
         }
     }
-}
 
-// Input parsing
-
-fn input_transform(line: &str) -> BoardLine {
-    line.chars()
-        .map(|c| match c {
-            '.' => Space::Empty(0),
-            '#' => Space::Blocked,
-            '^' => Space::Guard(Dir::N, 1),
-            _ => panic!("Invalid board char {c}"),
-        })
-        .collect()
+    gif.draw_frame(frame, delay)
 }
@@ -0,0 +1,120 @@
+use std::error::Error;
+
+use aoc::{
+    input::{InputSource, parse_input_vec},
+    solution::{Answer, Solution},
+};
+
+/// Day 7 solution
+#[derive(Clone)]
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: usize = 7;
+
+    type Input = Vec<Equation>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_input_vec(InputSource::Str(input), input_transform)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
+
+/// Sums the answers of the equations solvable with add and multiply
+pub fn part1(input: &[Equation]) -> u64 {
+    solveable_sum(input, false)
+}
+
+/// Sums the answers of the equations solvable with add, multiply and concatenate
+pub fn part2(input: &[Equation]) -> u64 {
+    solveable_sum(input, true)
+}
+
+fn solveable_sum(input: &[Equation], try_concat: bool) -> u64 {
+    input.iter().fold(0, |acc, e| {
+        if solveable(e, try_concat) {
+            acc + e.answer
+        } else {
+            acc
+        }
+    })
+}
+
+fn solveable(e: &Equation, try_concat: bool) -> bool {
+    solveable_iter(1, e.values[0], e, try_concat)
+}
+
+fn solveable_iter(idx: usize, res: u64, e: &Equation, try_concat: bool) -> bool {
+    // Any more values?
+    if idx == e.values.len() {
+        // No - check against answer
+        return res == e.answer;
+    }
+
+    // Try adding first
+    let next = res + e.values[idx];
+
+    if next <= e.answer && solveable_iter(idx + 1, next, e, try_concat) {
+        return true;
+    }
+
+    // Try multiplication
+    let next = res * e.values[idx];
+
+    if next <= e.answer && solveable_iter(idx + 1, next, e, try_concat) {
+        return true;
+    }
+
+    // Try concatenating the digits
+    if try_concat {
+        // Count digits in the next number
+        let digits = 1 + e.values[idx].ilog10();
+
+        // Multiply by 10^digits and add
+        let next = (res * 10u64.pow(digits)) + e.values[idx];
+
+        if next <= e.answer && solveable_iter(idx + 1, next, e, try_concat) {
+            return true;
+        }
+    }
+
+    false
+}
+
+pub struct Equation {
+    answer: u64,
+    values: Vec<u64>,
+}
+
+// Input parsing
+
+/// Parses an equation line
+pub fn input_transform(line: &str) -> Equation {
+    let mut s = line.split(':');
+
+    let answer = s
+        .next()
+        .expect("Answer not found")
+        .parse::<u64>()
+        .expect("Answer not valid");
+
+    let values = s
+        .next()
+        .expect("Values not found")
+        .trim_ascii_start()
+        .split_ascii_whitespace()
+        .map(|v| v.parse::<u64>().expect("Value not valid"))
+        .collect::<Vec<_>>();
+
+    Equation { answer, values }
+}
+
+#[cfg(test)]
+mod tests;
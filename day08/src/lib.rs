@@ -0,0 +1,98 @@
+use std::error::Error;
+
+use aoc::{
+    geom::Coord,
+    grid::Grid,
+    solution::{Answer, Solution},
+};
+use fxhash::{FxHashMap, FxHashSet};
+
+/// Day 8 solution
+#[derive(Clone)]
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: usize = 8;
+
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(Grid::parse(input, |c| c)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
+
+/// Counts the antinode positions one antenna spacing away
+pub fn part1(grid: &Grid<char>) -> u64 {
+    let mut intpos = FxHashSet::default();
+
+    for p in get_positions(grid).values() {
+        for (i, &p1) in p.iter().enumerate() {
+            for &p2 in &p[i + 1..] {
+                let diff = difference(p1, p2);
+
+                // Add the positions one spacing beyond each antenna
+                intpos.extend(grid.offset(p1, negate(diff)));
+                intpos.extend(grid.offset(p2, diff));
+            }
+        }
+    }
+
+    intpos.len() as u64
+}
+
+/// Counts the antinode positions in line with any two antennas
+pub fn part2(grid: &Grid<char>) -> u64 {
+    let mut intpos = FxHashSet::default();
+
+    for p in get_positions(grid).values() {
+        for (i, &p1) in p.iter().enumerate() {
+            for &p2 in &p[i + 1..] {
+                let diff = difference(p1, p2);
+
+                // Add the antenna and every position in line with it in both directions
+                intpos.insert(p1);
+                intpos.extend(grid.ray(p1, negate(diff)));
+                intpos.extend(grid.ray(p1, diff));
+            }
+        }
+    }
+
+    intpos.len() as u64
+}
+
+/// Returns the offset from one position to another
+fn difference(from: Coord<usize>, to: Coord<usize>) -> Coord<isize> {
+    Coord::new(
+        to.x as isize - from.x as isize,
+        to.y as isize - from.y as isize,
+    )
+}
+
+/// Returns an offset in the opposite direction
+fn negate(offset: Coord<isize>) -> Coord<isize> {
+    Coord::new(-offset.x, -offset.y)
+}
+
+/// Returns the antenna positions for each frequency
+fn get_positions(grid: &Grid<char>) -> FxHashMap<char, Vec<Coord<usize>>> {
+    let mut positions: FxHashMap<char, Vec<Coord<usize>>> = FxHashMap::default();
+
+    for (pos, c) in grid.iter() {
+        if *c != '.' {
+            positions.entry(*c).or_default().push(pos);
+        }
+    }
+
+    positions
+}
+
+#[cfg(test)]
+mod tests;
@@ -3,7 +3,7 @@ use std::{
     error::Error,
 };
 
-use aoc::{gif::Gif, input::parse_input_vec};
+use aoc::{gif::Canvas, input::parse_input_vec, vis::Options};
 use hsl::HSL;
 
 const CELLSIZE: usize = 18;
@@ -51,17 +51,25 @@ const ANTI: [[u8; CELLSIZE]; CELLSIZE] = [
 ];
 
 fn main() -> Result<(), Box<dyn Error>> {
+    // Get options
+    let options = Options::from_args()?;
+
     // Get input
     let input = parse_input_vec(8, input_transform)?;
 
     // Run parts
-    part1(&input, "vis/day08-1.gif")?;
-    part2(&input, "vis/day08-2.gif")?;
+    if options.wants_part(1) {
+        part1(&input, &options.for_part(1), "vis/day08-1.gif")?;
+    }
+
+    if options.wants_part(2) {
+        part2(&input, &options.for_part(2), "vis/day08-2.gif")?;
+    }
 
     Ok(())
 }
 
-fn part1(input: &[InputEnt], file: &str) -> Result<(), Box<dyn Error>> {
+fn part1(input: &[InputEnt], options: &Options, file: &str) -> Result<(), Box<dyn Error>> {
     let positions = get_positions(input);
 
     let mut intpos: HashMap<(usize, usize), HashSet<char>> = HashMap::new();
@@ -106,12 +114,12 @@ fn part1(input: &[InputEnt], file: &str) -> Result<(), Box<dyn Error>> {
 
     let (palette, layout) = build_pic(input, &positions, &intpos);
 
-    draw_pic(file, &palette, &layout)?;
+    draw_pic(options, file, &palette, &layout)?;
 
     Ok(())
 }
 
-fn part2(input: &[InputEnt], file: &str) -> Result<(), Box<dyn Error>> {
+fn part2(input: &[InputEnt], options: &Options, file: &str) -> Result<(), Box<dyn Error>> {
     let positions = get_positions(input);
 
     let mut intpos: HashMap<(usize, usize), HashSet<char>> = HashMap::new();
@@ -155,7 +163,7 @@ fn part2(input: &[InputEnt], file: &str) -> Result<(), Box<dyn Error>> {
 
     let (palette, layout) = build_pic(input, &positions, &intpos);
 
-    draw_pic(file, &palette, &layout)?;
+    draw_pic(options, file, &palette, &layout)?;
 
     Ok(())
 }
@@ -169,7 +177,7 @@ fn build_pic(
 
     let mut palette = vec![[0, 0, 0]];
 
-    let mut chars = positions.iter().map(|(c, _)| c).collect::<Vec<_>>();
+    let mut chars = positions.keys().collect::<Vec<_>>();
     chars.sort();
 
     let colinc = 360.0 / (chars.len() - 1) as f64;
@@ -227,17 +235,21 @@ fn build_pic(
     (palette, layout)
 }
 
-fn draw_pic(file: &str, palette: &[[u8; 3]], layout: &[Vec<Square>]) -> Result<(), Box<dyn Error>> {
-    let mut gif = Gif::new(
+fn draw_pic(
+    options: &Options,
+    file: &str,
+    palette: &[[u8; 3]],
+    layout: &[Vec<Square>],
+) -> Result<(), Box<dyn Error>> {
+    let mut gif = options.gif(
         file,
         palette,
         (layout[0].len() * CELLSIZE) as u16,
         (layout.len() * CELLSIZE) as u16,
         1,
-        1,
     )?;
 
-    let mut frame = gif.empty_frame();
+    let mut frame = gif.empty_canvas();
 
     for (y, l) in layout.iter().enumerate() {
         let gy = y * CELLSIZE;
@@ -246,31 +258,36 @@ fn draw_pic(file: &str, palette: &[[u8; 3]], layout: &[Vec<Square>]) -> Result<(
             let gx = x * CELLSIZE;
 
             if let Some(col) = c.outer {
-                for y in 0..CELLSIZE {
-                    for x in 0..CELLSIZE {
-                        if ANTENNA[y][x] != 0 {
-                            frame[gy + y][gx + x] = col as u8;
-                        }
-                    }
-                }
+                draw_mask(&mut frame, gx, gy, &ANTENNA, col as u8);
             }
 
             if let Some(col) = c.inner {
-                for y in 0..CELLSIZE {
-                    for x in 0..CELLSIZE {
-                        if ANTI[y][x] != 0 {
-                            frame[gy + y][gx + x] = col as u8;
-                        }
-                    }
-                }
+                draw_mask(&mut frame, gx, gy, &ANTI, col as u8);
             }
         }
     }
+
     gif.draw_frame(frame, 0)?;
 
     Ok(())
 }
 
+fn draw_mask(
+    frame: &mut Canvas,
+    gx: usize,
+    gy: usize,
+    mask: &[[u8; CELLSIZE]; CELLSIZE],
+    colour: u8,
+) {
+    for (y, l) in mask.iter().enumerate() {
+        for (x, m) in l.iter().enumerate() {
+            if *m != 0 {
+                frame[(gx + x, gy + y)] = colour;
+            }
+        }
+    }
+}
+
 #[derive(Default, Clone)]
 struct Square {
     outer: Option<usize>,
@@ -0,0 +1,185 @@
+use std::error::Error;
+
+use aoc::{
+    observer::Observer,
+    solution::{Answer, Solution},
+};
+
+/// Day 9 solution
+#[derive(Clone)]
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: usize = 9;
+
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(input.into())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Block1 {
+    Free,
+    Used(u16),
+}
+
+/// Returns the checksum after compacting individual blocks
+pub fn part1(input: &str) -> u64 {
+    let mut layout = Vec::new();
+
+    for (id, grp) in input.trim_ascii_end().as_bytes().chunks(2).enumerate() {
+        layout.extend(vec![Block1::Used(id as u16); (grp[0] - b'0') as usize]);
+
+        if grp.len() > 1 {
+            layout.extend(vec![Block1::Free; (grp[1] - b'0') as usize]);
+        }
+    }
+
+    let mut free_ptr = 0;
+    let mut occ_ptr = layout.len() - 1;
+
+    loop {
+        // Move free ptr
+        while matches!(layout[free_ptr], Block1::Used(_)) {
+            free_ptr += 1;
+        }
+
+        // Move occupied ptr
+        while matches!(layout[occ_ptr], Block1::Free) {
+            occ_ptr -= 1;
+        }
+
+        // Check
+        if occ_ptr < free_ptr {
+            break;
+        }
+
+        // Update
+        layout[free_ptr] = layout[occ_ptr];
+        layout[occ_ptr] = Block1::Free;
+    }
+
+    layout[..free_ptr]
+        .iter()
+        .enumerate()
+        .map(|(i, b)| match b {
+            Block1::Used(bn) => i as u64 * *bn as u64,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Contiguous run of disk blocks
+pub struct Block2 {
+    pub pos: u32,
+    pub len: u8,
+}
+
+/// Events emitted while compacting files in part 2
+pub enum Event {
+    /// A file was relocated to a free span to its left
+    Relocated { from: u32, to: u32, len: u8 },
+    /// A file could not be relocated
+    Kept { pos: u32, len: u8 },
+}
+
+/// Parses the disk map in to file and free spans
+pub fn parse_disk(input: &str) -> (Vec<Block2>, Vec<Block2>) {
+    let mut files = Vec::new();
+    let mut free = Vec::new();
+
+    let mut pos: u32 = 0;
+
+    for grp in input.trim_ascii_end().as_bytes().chunks(2) {
+        let len = grp[0] - b'0';
+        files.push(Block2 { pos, len });
+        pos += len as u32;
+
+        if grp.len() > 1 {
+            let len = grp[1] - b'0';
+            if len > 0 {
+                free.push(Block2 { pos, len });
+                pos += len as u32;
+            }
+        }
+    }
+
+    (files, free)
+}
+
+/// Returns the checksum after compacting whole files
+pub fn part2(input: &str) -> u64 {
+    compact_files(input, &mut ())
+}
+
+/// Compacts whole files and returns the filesystem checksum
+pub fn compact_files<O>(input: &str, observer: &mut O) -> u64
+where
+    O: Observer<Event>,
+{
+    let (mut alloc_in, mut free) = parse_disk(input);
+
+    let mut alloc_out = Vec::new();
+
+    while let Some(a) = alloc_in.pop() {
+        // Find first free
+        if free[0].pos > a.pos {
+            alloc_in.push(a);
+            break;
+        }
+
+        if let Some(f) = free.iter().position(|f| f.len >= a.len && f.pos < a.pos) {
+            // Move to free block
+            observer.event(Event::Relocated {
+                from: a.pos,
+                to: free[f].pos,
+                len: a.len,
+            });
+
+            alloc_out.push(Block2 {
+                pos: free[f].pos,
+                len: a.len,
+            });
+
+            // Adjust / remove free block
+            if free[f].len > a.len {
+                free[f].pos += a.len as u32;
+                free[f].len -= a.len;
+            } else {
+                free.remove(f);
+            }
+        } else {
+            // Don't move
+            observer.event(Event::Kept {
+                pos: a.pos,
+                len: a.len,
+            });
+
+            alloc_out.push(a)
+        }
+    }
+
+    alloc_in
+        .iter()
+        .chain(alloc_out.iter().rev())
+        .enumerate()
+        .map(|(id, a)| {
+            (0..a.len)
+                .map(|i| (a.pos + i as u32) as u64 * id as u64)
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests;
@@ -2,12 +2,19 @@ use std::error::Error;
 
 use aoc::gif::Gif;
 use aoc::input::parse_input;
+use aoc::vis::Options;
+use day09::{Block2, Event, compact_files, parse_disk};
 
 fn main() -> Result<(), Box<dyn Error>> {
+    // Get options
+    let options = Options::from_args()?;
+
     // Get input
     let input = parse_input(9, |s| s.to_string())?;
 
-    part2(&input)?;
+    if options.wants_part(2) {
+        part2(&input, &options)?;
+    }
 
     Ok(())
 }
@@ -16,82 +23,69 @@ const XDIM: usize = 380;
 const YDIM: usize = 260;
 const SCALE: usize = 3;
 
-struct Block2 {
-    pos: u32,
-    len: u8,
-}
+fn part2(input: &str, options: &Options) -> Result<(), Box<dyn Error>> {
+    let (files, mut free) = parse_disk(input);
 
-fn part2(input: &str) -> Result<(), Box<dyn Error>> {
-    let mut alloc_in = Vec::new();
-    let mut free = Vec::new();
+    // Files paired with whether they have been moved
+    let mut files = files.into_iter().map(|f| (false, f)).collect::<Vec<_>>();
     let mut freed = Vec::new();
 
-    let mut pos: u32 = 0;
-
-    for grp in input.trim_ascii_end().as_bytes().chunks(2) {
-        let len = grp[0] - b'0';
-        alloc_in.push(Block2 { pos, len });
-        pos += len as u32;
-
-        if grp.len() > 1 {
-            let len = grp[1] - b'0';
-            if len > 0 {
-                free.push(Block2 { pos, len });
-                pos += len as u32;
-            }
-        }
-    }
-
-    let mut alloc_out = Vec::new();
-
-    let palette = vec![[0, 0, 0], [64, 255, 64], [255, 255, 64], [64, 0, 0], [
-        128, 0, 0,
-    ]];
+    let palette = vec![
+        [0, 0, 0],
+        [64, 255, 64],
+        [255, 255, 64],
+        [64, 0, 0],
+        [128, 0, 0],
+    ];
 
-    let mut gif = Gif::new(
+    let mut gif = options.gif(
         "vis/day09-2.gif",
         &palette,
         (XDIM * SCALE) as u16 + 1,
         (YDIM * SCALE) as u16 + 1,
         1,
-        1,
     )?;
 
-    draw_frame(&mut gif, &alloc_in, &alloc_out, &free, &freed)?;
+    let frame_skip = options.frame_skip(1);
+    let delay = options.delay(2);
+
+    draw_frame(&mut gif, &files, &free, &freed, delay)?;
+
+    // Draw a frame as each file is considered
+    let mut result = Ok(());
+    let mut events = 0;
 
-    while let Some(a) = alloc_in.pop() {
-        // Find first free
-        if free[0].pos > a.pos {
-            alloc_in.push(a);
-            break;
+    compact_files(input, &mut |event| {
+        if result.is_err() {
+            return;
         }
 
-        if let Some(f) = free.iter().position(|f| f.len >= a.len && f.pos < a.pos) {
-            // Move to free block
-            alloc_out.push((true, Block2 {
-                pos: free[f].pos,
-                len: a.len,
-            }));
+        if let Event::Relocated { from, to, len } = event {
+            // Move the file
+            let file = files.iter_mut().find(|(_, f)| f.pos == from).unwrap();
+            *file = (true, Block2 { pos: to, len });
 
             // Adjust / remove free block
-            if free[f].len > a.len {
-                free[f].pos += a.len as u32;
-                free[f].len -= a.len;
+            let f = free.iter().position(|f| f.pos == to).unwrap();
+
+            if free[f].len > len {
+                free[f].pos += len as u32;
+                free[f].len -= len;
             } else {
                 free.remove(f);
             }
 
-            freed.push(Block2 {
-                pos: a.pos,
-                len: a.len,
-            });
-        } else {
-            // Don't move
-            alloc_out.push((false, a))
+            freed.push(Block2 { pos: from, len });
         }
 
-        draw_frame(&mut gif, &alloc_in, &alloc_out, &free, &freed)?;
-    }
+        events += 1;
+
+        if events % frame_skip == 0 {
+            result = draw_frame(&mut gif, &files, &free, &freed, delay);
+        }
+    });
+
+    result?;
 
     gif.delay(500)?;
 
@@ -100,10 +94,10 @@ fn part2(input: &str) -> Result<(), Box<dyn Error>> {
 
 fn draw_frame(
     gif: &mut Gif,
-    alloc_in: &[Block2],
-    alloc_out: &[(bool, Block2)],
+    files: &[(bool, Block2)],
     free: &[Block2],
     freed: &[Block2],
+    delay: u16,
 ) -> Result<(), Box<dyn Error>> {
     let mut frame = gif.empty_frame();
 
@@ -123,11 +117,7 @@ fn draw_frame(
         }
     };
 
-    for a in alloc_in.iter() {
-        draw_block(a, 1);
-    }
-
-    for (moved, a) in alloc_out.iter() {
+    for (moved, a) in files.iter() {
         draw_block(a, if *moved { 2 } else { 1 });
     }
 
@@ -139,7 +129,7 @@ fn draw_frame(
         draw_block(f, 4);
     }
 
-    gif.draw_frame_identical_check(frame, 2, aoc::gif::IdenticalAction::Ignore)?;
+    gif.draw_frame_identical_check(frame, delay, aoc::gif::IdenticalAction::Ignore)?;
 
     Ok(())
 }
@@ -0,0 +1,80 @@
+use std::error::Error;
+
+use aoc::{
+    geom::Coord,
+    grid::Grid,
+    solution::{Answer, Solution},
+};
+use fxhash::FxHashSet;
+
+/// Day 10 solution
+#[derive(Clone)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: usize = 10;
+
+    type Input = Grid<u8>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(Grid::parse_try(input, input_transform)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
+
+/// Sums the number of peaks reachable from each trailhead
+pub fn part1(grid: &Grid<u8>) -> u64 {
+    grid.find_all(&0)
+        .map(|pos| {
+            let mut dests = FxHashSet::default();
+
+            walk1(grid, pos, 1, &mut dests);
+
+            dests.len() as u64
+        })
+        .sum()
+}
+
+fn walk1(grid: &Grid<u8>, pos: Coord<usize>, h: u8, dests: &mut FxHashSet<Coord<usize>>) {
+    pos_from(grid, pos, h).for_each(|npos| {
+        if h == 9 {
+            dests.insert(npos);
+        } else {
+            walk1(grid, npos, h + 1, dests);
+        }
+    })
+}
+
+/// Sums the number of distinct trails from each trailhead
+pub fn part2(grid: &Grid<u8>) -> u64 {
+    grid.find_all(&0).map(|pos| walk2(grid, pos, 1)).sum()
+}
+
+fn walk2(grid: &Grid<u8>, pos: Coord<usize>, h: u8) -> u64 {
+    pos_from(grid, pos, h)
+        .map(|npos| if h == 9 { 1 } else { walk2(grid, npos, h + 1) })
+        .sum()
+}
+
+fn pos_from(grid: &Grid<u8>, pos: Coord<usize>, h: u8) -> impl Iterator<Item = Coord<usize>> + '_ {
+    grid.neighbours4(pos).filter(move |&npos| grid[npos] == h)
+}
+
+// Input parsing
+
+/// Parses a height digit
+pub fn input_transform(c: char) -> Result<u8, String> {
+    c.to_digit(10)
+        .map(|h| h as u8)
+        .ok_or_else(|| format!("Invalid height {c}"))
+}
+
+#[cfg(test)]
+mod tests;
@@ -1,5 +1,3 @@
-use aoc::input::parse_test_vec;
-
 use super::*;
 
 const EXAMPLE1: &str = "\
@@ -15,7 +13,7 @@ const EXAMPLE1: &str = "\
 
 #[test]
 fn test1() {
-    let input = parse_test_vec(EXAMPLE1, input_transform).unwrap();
+    let input = Grid::parse_try(EXAMPLE1, input_transform).unwrap();
     assert_eq!(part1(&input), 36);
     assert_eq!(part2(&input), 81);
 }
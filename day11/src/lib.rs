@@ -0,0 +1,166 @@
+use std::{collections::VecDeque, error::Error};
+
+use aoc::{
+    params::{parse_value, unknown},
+    solution::{Answer, Solution},
+};
+use fxhash::FxHashMap;
+
+/// Day 11 solution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day11 {
+    /// Number of blinks for part 1
+    pub blinks1: u8,
+    /// Number of blinks for part 2
+    pub blinks2: u8,
+}
+
+impl Day11 {
+    /// Parameters for the puzzle input
+    pub const INPUT: Self = Self {
+        blinks1: 25,
+        blinks2: 75,
+    };
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self::INPUT
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: usize = 11;
+
+    type Input = InputEnt;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let line = input.lines().next().ok_or("No line in input")?;
+
+        Ok(input_transform(line))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        count(input, self.blinks1).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<Answer> {
+        Some(count(input, self.blinks2).into())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("blinks1", self.blinks1.to_string()),
+            ("blinks2", self.blinks2.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Box<dyn Error>> {
+        match name {
+            "blinks1" => self.blinks1 = parse_value(name, value)?,
+            "blinks2" => self.blinks2 = parse_value(name, value)?,
+            _ => Err(unknown(name))?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Counts the stones after a number of blinks
+pub fn count(input: &InputEnt, iters: u8) -> u64 {
+    let mut work = VecDeque::new();
+    let mut note = FxHashMap::default();
+
+    // Build initial work queue
+    (0..input.len()).for_each(|i| {
+        work.push_back((input[i], iters));
+    });
+
+    // Process work queue
+    while let Some((num, iters)) = work.pop_front() {
+        let mut rework = true;
+
+        if num == 0 {
+            // 0 -> 1
+            if iters == 1 {
+                note.insert((num, iters), 1u64);
+                rework = false;
+            } else if let Some(count) = note.get(&(1, iters - 1)) {
+                note.insert((num, iters), *count);
+                rework = false;
+            } else {
+                work.push_front((1, iters - 1));
+            }
+        } else {
+            let log10 = num.ilog10();
+
+            if (log10 & 1) == 1 {
+                // Split even number
+                if iters == 1 {
+                    note.insert((num, iters), 2u64);
+                    rework = false;
+                } else {
+                    let div = 10u64.pow(log10.div_ceil(2));
+
+                    let num1 = num / div;
+                    let num2 = num % div;
+
+                    match (note.get(&(num1, iters - 1)), note.get(&(num2, iters - 1))) {
+                        (Some(count1), Some(count2)) => {
+                            note.insert((num, iters), count1 + count2);
+                            rework = false;
+                        }
+                        (Some(_), None) => {
+                            work.push_front((num2, iters - 1));
+                        }
+                        (None, Some(_)) => {
+                            work.push_front((num1, iters - 1));
+                        }
+                        (None, None) => {
+                            work.push_front((num2, iters - 1));
+                            work.push_front((num1, iters - 1));
+                        }
+                    }
+                }
+            } else {
+                // Odd number - multiply by 2024
+                if iters == 1 {
+                    note.insert((num, iters), 1u64);
+                    rework = false;
+                } else {
+                    let new = num * 2024;
+
+                    if let Some(count) = note.get(&(new, iters - 1)) {
+                        note.insert((num, iters), *count);
+                        rework = false;
+                    } else {
+                        work.push_front((new, iters - 1));
+                    }
+                }
+            }
+        }
+
+        if rework {
+            work.push_back((num, iters));
+        }
+    }
+
+    input
+        .iter()
+        .map(|num| *note.get(&(*num, iters)).unwrap())
+        .sum()
+}
+
+// Input parsing
+
+pub type InputEnt = Vec<u64>;
+
+/// Parses the line of stones
+pub fn input_transform(line: &str) -> InputEnt {
+    line.split_ascii_whitespace()
+        .map(|ns| ns.parse::<u64>().expect("not an integer"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
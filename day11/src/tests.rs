@@ -18,5 +18,5 @@ fn test2() {
 #[test]
 fn test3() {
     let input = input_transform(EXAMPLE2);
-    assert_eq!(part1(&input), 55312);
+    assert_eq!(count(&input, 25), 55312);
 }
@@ -0,0 +1,233 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    error::Error,
+};
+
+use aoc::{
+    grid::Grid,
+    observer::Observer,
+    solution::{Answer, Solution},
+};
+use fxhash::FxHashSet;
+
+/// Day 12 solution
+#[derive(Clone)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: usize = 12;
+
+    type Input = Vec<Shape>;
+
+    const SHARED_PARSE: bool = true;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let grid = Grid::parse(input, |c| c)?;
+
+        Ok(get_shapes(&grid, &mut ()))
+    }
+
+    fn part1(&self, shapes: &Self::Input) -> Answer {
+        part1(shapes).into()
+    }
+
+    fn part2(&self, shapes: &Self::Input) -> Option<Answer> {
+        Some(part2(shapes).into())
+    }
+}
+
+/// Sums the fence price of each region using its perimeter
+pub fn part1(shapes: &[Shape]) -> u64 {
+    shapes.iter().map(|s| s.area * s.perimeter).sum()
+}
+
+/// Sums the fence price of each region using its number of sides
+pub fn part2(shapes: &[Shape]) -> u64 {
+    shapes.iter().map(|s| s.area * s.sides).sum()
+}
+
+pub type Coord = aoc::geom::Coord<usize>;
+
+/// Region area, perimeter and number of sides
+#[derive(Debug)]
+pub struct Shape {
+    area: u64,
+    perimeter: u64,
+    sides: u64,
+}
+
+/// Events emitted while finding regions
+pub enum Event<'a> {
+    /// A region of a plant type was flood filled
+    Region {
+        plant: char,
+        squares: &'a FxHashSet<Coord>,
+    },
+}
+
+/// Finds each region of the garden
+pub fn get_shapes<O>(grid: &Grid<char>, observer: &mut O) -> Vec<Shape>
+where
+    O: for<'a> Observer<Event<'a>>,
+{
+    let mut shapes = Vec::new();
+    let mut touched: FxHashSet<Coord> = FxHashSet::default();
+
+    for (pos, &plant) in grid.iter() {
+        if !touched.contains(&pos) {
+            // Get shape topology
+            let (squares, xbounds, ybounds) = shape_topology(grid, pos);
+
+            // Add squares to touched list
+            touched.extend(&squares);
+
+            observer.event(Event::Region {
+                plant,
+                squares: &squares,
+            });
+
+            // Get perimeter and side count
+            let (perimeter, sides) = perimeter_sides(&squares, &xbounds, &ybounds);
+
+            // Add shape
+            shapes.push(Shape {
+                area: squares.len() as u64,
+                perimeter,
+                sides,
+            })
+        }
+    }
+
+    shapes
+}
+
+fn perimeter_sides(
+    squares: &FxHashSet<Coord>,
+    xbounds: &BTreeMap<usize, (usize, usize)>,
+    ybounds: &BTreeMap<usize, (usize, usize)>,
+) -> (u64, u64) {
+    let mut perimeter = 0;
+    let mut sides = 0;
+
+    // Horizontal edges
+    for (&y, &(xmin, xmax)) in xbounds {
+        let mut lasttop = false;
+        let mut lastbottom = false;
+
+        for x in xmin..=xmax {
+            if squares.contains(&Coord::new(x, y)) {
+                if y == 0 || !squares.contains(&Coord::new(x, y - 1)) {
+                    perimeter += 1;
+
+                    if !lasttop {
+                        sides += 1;
+                        lasttop = true;
+                    }
+                } else {
+                    lasttop = false;
+                }
+
+                if !squares.contains(&Coord::new(x, y + 1)) {
+                    perimeter += 1;
+
+                    if !lastbottom {
+                        sides += 1;
+                        lastbottom = true;
+                    }
+                } else {
+                    lastbottom = false;
+                }
+            } else {
+                lasttop = false;
+                lastbottom = false;
+            }
+        }
+    }
+
+    // Vertical edges
+    for (&x, &(ymin, ymax)) in ybounds {
+        let mut lastleft = false;
+        let mut lastright = false;
+
+        for y in ymin..=ymax {
+            if squares.contains(&Coord::new(x, y)) {
+                if x == 0 || !squares.contains(&Coord::new(x - 1, y)) {
+                    perimeter += 1;
+
+                    if !lastleft {
+                        sides += 1;
+                        lastleft = true;
+                    }
+                } else {
+                    lastleft = false;
+                }
+
+                if !squares.contains(&Coord::new(x + 1, y)) {
+                    perimeter += 1;
+
+                    if !lastright {
+                        sides += 1;
+                        lastright = true;
+                    }
+                } else {
+                    lastright = false;
+                }
+            } else {
+                lastleft = false;
+                lastright = false;
+            }
+        }
+    }
+
+    (perimeter, sides)
+}
+
+type ShapeTopology = (
+    FxHashSet<Coord>,
+    BTreeMap<usize, (usize, usize)>,
+    BTreeMap<usize, (usize, usize)>,
+);
+
+fn shape_topology(grid: &Grid<char>, pos: Coord) -> ShapeTopology {
+    let mut squares = FxHashSet::default();
+    let mut xbounds = BTreeMap::new();
+    let mut ybounds = BTreeMap::new();
+
+    let c = grid[pos];
+    let mut work = VecDeque::new();
+
+    // Add initial coordinate to squares set
+    squares.insert(pos);
+
+    // Add first work item
+    work.push_back(pos);
+
+    // Process work queue
+    while let Some(pos) = work.pop_front() {
+        // Update x and y bounds
+        update_bounds(&mut xbounds, pos.y, pos.x);
+        update_bounds(&mut ybounds, pos.x, pos.y);
+
+        // Do flood fill step
+        for npos in grid.neighbours4(pos).filter(|&npos| grid[npos] == c) {
+            if squares.insert(npos) {
+                work.push_back(npos);
+            }
+        }
+    }
+
+    (squares, xbounds, ybounds)
+}
+
+fn update_bounds(bounds: &mut BTreeMap<usize, (usize, usize)>, key: usize, value: usize) {
+    bounds
+        .entry(key)
+        .and_modify(|(min, max)| {
+            *min = value.min(*min);
+            *max = value.max(*max);
+        })
+        .or_insert((value, value));
+}
+
+#[cfg(test)]
+mod tests;
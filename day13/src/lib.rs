@@ -0,0 +1,140 @@
+use std::error::Error;
+
+use aoc::{
+    input::sections,
+    params::{parse_value, unknown},
+    solution::{Answer, Solution},
+};
+use regex::Regex;
+
+/// Day 13 solution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day13 {
+    /// Amount added to each prize coordinate for part 2
+    pub offset: u64,
+}
+
+impl Day13 {
+    /// Parameters for the puzzle input
+    pub const INPUT: Self = Self {
+        offset: 10000000000000,
+    };
+}
+
+impl Default for Day13 {
+    fn default() -> Self {
+        Self::INPUT
+    }
+}
+
+impl Solution for Day13 {
+    const DAY: usize = 13;
+
+    type Input = Vec<Claw>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_input_str(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<Answer> {
+        Some(part2(self.offset, input).into())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("offset", self.offset.to_string())]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Box<dyn Error>> {
+        match name {
+            "offset" => self.offset = parse_value(name, value)?,
+            _ => Err(unknown(name))?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Sums the tokens needed to win every winnable prize
+pub fn part1(input: &[Claw]) -> u64 {
+    input
+        .iter()
+        .filter_map(|c| presses(c, 0))
+        .map(|(apresses, bpresses)| (apresses * 3) + bpresses)
+        .sum()
+}
+
+/// Sums the tokens needed to win every winnable prize with the offset positions
+pub fn part2(offset: u64, input: &[Claw]) -> u64 {
+    input
+        .iter()
+        .filter_map(|c| presses(c, offset))
+        .map(|(apresses, bpresses)| (apresses * 3) + bpresses)
+        .sum()
+}
+
+fn presses(c: &Claw, adjust: u64) -> Option<(u64, u64)> {
+    // Find line intersection
+    let ax = c.a.0 as f64;
+    let ay = c.a.1 as f64;
+
+    let bx = c.b.0 as f64;
+    let by = c.b.1 as f64;
+
+    let tx = (c.target.0 + adjust) as f64;
+    let ty = (c.target.1 + adjust) as f64;
+
+    let denom = (ax * by) - (ay * bx);
+
+    let apresses = (tx * by - ty * bx) / denom;
+    let bpresses = (ty * ax - tx * ay) / denom;
+
+    if apresses.fract() != 0.0 || bpresses.fract() != 0.0 {
+        None
+    } else {
+        Some((apresses as u64, bpresses as u64))
+    }
+}
+
+// Input parsing
+
+pub type Coord = (u64, u64);
+
+#[derive(Debug)]
+pub struct Claw {
+    a: Coord,
+    b: Coord,
+    target: Coord,
+}
+
+/// Parses the claw machine descriptions
+pub fn parse_input_str(file: &str) -> Vec<Claw> {
+    let re = Regex::new(r"\d+").expect("Failed to create regex");
+
+    sections(file)
+        .map(|chunk| {
+            let mut tuples = chunk.lines().map(|line| {
+                let mut captures = re
+                    .find_iter(line)
+                    .map(|c| c.as_str().parse::<u64>().unwrap());
+
+                (
+                    captures.next().expect("First u64 not present"),
+                    captures.next().expect("Second u64 not present"),
+                )
+            });
+
+            Claw {
+                a: tuples.next().expect("First tuple not present"),
+                b: tuples.next().expect("Second tuple not present"),
+                target: tuples.next().expect("Third tuple not present"),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
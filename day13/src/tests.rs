@@ -22,5 +22,5 @@ Prize: X=18641, Y=10279
 fn test1() {
     let input = parse_input_str(EXAMPLE1);
     assert_eq!(part1(&input), 480);
-    assert_eq!(part2(&input), 875318608908);
+    assert_eq!(part2(10000000000000, &input), 875318608908);
 }
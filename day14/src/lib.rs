@@ -0,0 +1,236 @@
+use std::{error::Error, ops::Range, sync::LazyLock};
+
+use aoc::{
+    geom::Coord,
+    input::{InputSource, parse_input_vec},
+    observer::Observer,
+    params::{parse_value, unknown},
+    solution::{Answer, Solution},
+};
+use fxhash::FxHashSet;
+use regex::Regex;
+
+/// Day 14 solution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day14 {
+    /// Width of the room
+    pub width: usize,
+    /// Height of the room
+    pub height: usize,
+    /// Number of seconds to move the robots for part 1
+    pub seconds: u64,
+}
+
+impl Day14 {
+    /// Parameters for the puzzle input
+    pub const INPUT: Self = Self {
+        width: 101,
+        height: 103,
+        seconds: 100,
+    };
+
+    /// Parameters for the example
+    pub const EXAMPLE: Self = Self {
+        width: 11,
+        height: 7,
+        seconds: 100,
+    };
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self::INPUT
+    }
+}
+
+impl Solution for Day14 {
+    const DAY: usize = 14;
+
+    type Input = Vec<Robot>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let input = parse_input_vec(InputSource::Str(input), input_transform)?;
+
+        // Check the robots start inside the room
+        if let Some(r) = input
+            .iter()
+            .find(|r| r.pos.x >= self.width || r.pos.y >= self.height)
+        {
+            Err(format!(
+                "Robot at {},{} is outside the room",
+                r.pos.x, r.pos.y
+            ))?
+        }
+
+        Ok(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(self.seconds, Board::new(self.width, self.height, input)).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<Answer> {
+        Some(match part2(Board::new(self.width, self.height, input)) {
+            Some(secs) => secs.into(),
+            None => "No solution".into(),
+        })
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("width", self.width.to_string()),
+            ("height", self.height.to_string()),
+            ("seconds", self.seconds.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Box<dyn Error>> {
+        match name {
+            "width" => self.width = parse_value(name, value)?,
+            "height" => self.height = parse_value(name, value)?,
+            "seconds" => self.seconds = parse_value(name, value)?,
+            _ => Err(unknown(name))?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the safety factor after a number of seconds
+pub fn part1(seconds: u64, mut board: Board) -> u64 {
+    for _ in 0..seconds {
+        board.step();
+    }
+
+    let qx = board.w / 2;
+    let qy = board.h / 2;
+
+    let mut qr = [0; 4];
+
+    let mut check = |r: &Robot, i, xr: Range<usize>, yr: Range<usize>| {
+        if xr.contains(&r.pos.x) && yr.contains(&r.pos.y) {
+            qr[i] += 1;
+        }
+    };
+
+    for r in board.robots.iter() {
+        check(r, 0, 0..qx, 0..qy);
+        check(r, 1, board.w - qx..board.w, 0..qy);
+        check(r, 2, 0..qx, board.h - qy..board.h);
+        check(r, 3, board.w - qx..board.w, board.h - qy..board.h);
+    }
+
+    qr.iter().product::<u64>()
+}
+
+/// Returns the number of seconds until the robots form a picture
+pub fn part2(board: Board) -> Option<u64> {
+    find_picture(board, &mut ())
+}
+
+/// Events emitted while stepping the robots
+pub enum Event<'a> {
+    /// The robots have moved one step
+    Stepped { secs: u64, robots: &'a [Robot] },
+}
+
+/// Steps the robots until none overlap, returning the number of seconds taken.
+/// The robots return to their starting positions within width * height steps,
+/// so None is returned if no picture is found by then
+pub fn find_picture<O>(mut board: Board, observer: &mut O) -> Option<u64>
+where
+    O: for<'a> Observer<Event<'a>>,
+{
+    let limit = (board.w as u64).saturating_mul(board.h as u64);
+
+    for secs in 1..=limit {
+        board.step();
+
+        observer.event(Event::Stepped {
+            secs,
+            robots: &board.robots,
+        });
+
+        if board.interesting() {
+            return Some(secs);
+        }
+    }
+
+    None
+}
+
+/// Robot position and velocity
+#[derive(Debug, Clone)]
+pub struct Robot {
+    pub pos: Coord<usize>,
+    pub vel: Coord<isize>,
+}
+
+/// Robots moving on a wrapping board
+pub struct Board {
+    w: usize,
+    h: usize,
+    robots: Vec<Robot>,
+}
+
+impl Board {
+    /// Creates a board with a set of robots
+    pub fn new(w: usize, h: usize, robots: &[Robot]) -> Self {
+        Self {
+            w,
+            h,
+            robots: robots.to_vec(),
+        }
+    }
+
+    fn step(&mut self) {
+        let bounds = Coord::new(self.w, self.h);
+
+        self.robots.iter_mut().for_each(|r| {
+            r.pos = r.pos.add_mod(r.vel, bounds);
+        })
+    }
+
+    fn interesting(&self) -> bool {
+        // No overlaps
+        let mut set = FxHashSet::default();
+
+        for r in &self.robots {
+            if !set.insert(r.pos) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+// Input parsing
+
+pub type InputEnt = Robot;
+
+static RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap());
+
+/// Parses a robot
+pub fn input_transform(line: &str) -> InputEnt {
+    let c: [&str; 4] = RE
+        .captures(line)
+        .map(|c| c.extract())
+        .map(|(_, arr)| arr)
+        .expect("Pattern not found");
+
+    Robot {
+        pos: Coord::new(
+            c[0].parse::<usize>().unwrap(),
+            c[1].parse::<usize>().unwrap(),
+        ),
+        vel: Coord::new(
+            c[2].parse::<isize>().unwrap(),
+            c[3].parse::<isize>().unwrap(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -22,5 +22,33 @@ fn test2() {
     let input = parse_test_vec(EXAMPLE1, input_transform).unwrap();
 
     let board = Board::new(11, 7, &input);
-    assert_eq!(part1(board), 12);
+    assert_eq!(part1(100, board), 12);
+}
+
+#[test]
+fn test_params() {
+    let mut day = Day14::default();
+
+    day.set_param("width", "11").unwrap();
+    day.set_param("height", "7").unwrap();
+    assert_eq!(day, Day14::EXAMPLE);
+
+    let input = day.parse(EXAMPLE1).unwrap();
+    assert_eq!(day.part1(&input), Answer::Int(12));
+
+    // Robots must start inside the room
+    day.set_param("width", "10").unwrap();
+    assert!(day.parse(EXAMPLE1).is_err());
+}
+
+#[test]
+fn test_no_picture() {
+    // Robots moving together never separate
+    let input = parse_test_vec("p=0,0 v=1,1\np=0,0 v=1,1\n", input_transform).unwrap();
+
+    assert_eq!(part2(Board::new(3, 3, &input)), None);
+    assert_eq!(
+        Day14::EXAMPLE.part2(&input),
+        Some(Answer::Str("No solution".into()))
+    );
 }
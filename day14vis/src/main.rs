@@ -1,62 +1,84 @@
-use std::{collections::HashSet, error::Error, sync::LazyLock};
+use std::{collections::VecDeque, error::Error};
 
-use aoc::{gif::Gif, input::parse_input_vec};
-use regex::Regex;
+use aoc::{
+    geom::Coord,
+    gif::{CaptionBar, Format, Gif},
+    input::parse_input_vec,
+    vis::Options,
+};
+use day14::{Board, Day14, Event, Robot, find_picture, input_transform};
+
+const W: usize = Day14::INPUT.width;
+const H: usize = Day14::INPUT.height;
 
 fn main() -> Result<(), Box<dyn Error>> {
+    // Get options
+    let options = Options::from_args()?;
+
     // Get input
     let input = parse_input_vec(14, input_transform)?;
 
-    // Count secs
-    let board = Board::new(101, 103, &input);
-    let secs = count(board);
+    // Keep the robot positions for the last 100 steps
+    let mut history = VecDeque::new();
+
+    find_picture(Board::new(W, H, &input), &mut |event: Event| match event {
+        Event::Stepped { secs, robots } => {
+            if history.len() == 100 {
+                history.pop_front();
+            }
+
+            history.push_back((secs, positions(robots)));
+        }
+    })
+    .ok_or("No picture found")?;
 
     // Draw
-    let board = Board::new(101, 103, &input);
-    draw(board, secs)?;
+    draw(&options, positions(&input), history)?;
 
     Ok(())
 }
 
-fn count(mut board: Board) -> usize {
-    let mut secs = 0;
-
-    loop {
-        board.step();
-        secs += 1;
-
-        if board.interesting() {
-            break;
-        }
-    }
-
-    secs
+fn positions(robots: &[Robot]) -> Vec<Coord<usize>> {
+    robots.iter().map(|r| r.pos).collect()
 }
 
 const SCALE: u16 = 8;
 
-fn draw(mut board: Board, secs: usize) -> Result<(), Box<dyn Error>> {
-    let palette = vec![[0, 0, 0], [0, 255, 0]];
-
-    let mut gif = Gif::new(
+fn draw(
+    options: &Options,
+    start: Vec<Coord<usize>>,
+    history: VecDeque<(u64, Vec<Coord<usize>>)>,
+) -> Result<(), Box<dyn Error>> {
+    let palette = vec![[0, 0, 0], [0, 255, 0], [48, 48, 48]];
+
+    // Watch unscaled in the terminal
+    let scale = if options.format() == Format::Term {
+        1
+    } else {
+        SCALE
+    };
+
+    // Status bar for the second count
+    let caption = CaptionBar {
+        scale: if scale == 1 { 1 } else { 3 },
+        ..CaptionBar::new(1, 1, 2)
+    };
+
+    let mut gif = options.gif_with_caption(
         "vis/day14.gif",
         &palette,
-        board.w as u16,
-        board.h as u16,
-        SCALE,
-        SCALE,
+        W as u16,
+        H as u16,
+        scale,
+        caption,
     )?;
 
-    draw_board(&mut gif, &board)?;
-
-    let draw_from = secs - 100;
+    let delay = options.delay(5);
 
-    for s in 0..secs {
-        board.step();
+    draw_board(&mut gif, 0, &start, delay)?;
 
-        if s > draw_from {
-            draw_board(&mut gif, &board)?;
-        }
+    for (secs, robots) in history {
+        draw_board(&mut gif, secs, &robots, delay)?;
     }
 
     gif.delay(500)?;
@@ -64,80 +86,20 @@ fn draw(mut board: Board, secs: usize) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn draw_board(gif: &mut Gif, board: &Board) -> Result<(), Box<dyn Error>> {
+fn draw_board(
+    gif: &mut Gif,
+    secs: u64,
+    robots: &[Coord<usize>],
+    delay: u16,
+) -> Result<(), Box<dyn Error>> {
     let mut frame = gif.empty_frame();
 
-    for r in &board.robots {
+    for r in robots {
         frame[r.y][r.x] = 1;
     }
 
-    gif.draw_frame(frame, 5)?;
+    gif.set_caption(&format!("Seconds: {secs}"));
+    gif.draw_frame(frame, delay)?;
 
     Ok(())
 }
-
-#[derive(Debug, Clone)]
-struct Robot {
-    x: usize,
-    y: usize,
-    vx: isize,
-    vy: isize,
-}
-
-struct Board {
-    w: usize,
-    h: usize,
-    robots: Vec<Robot>,
-}
-
-impl Board {
-    fn new(w: usize, h: usize, robots: &[Robot]) -> Self {
-        Self {
-            w,
-            h,
-            robots: robots.to_vec(),
-        }
-    }
-
-    fn step(&mut self) {
-        self.robots.iter_mut().for_each(|r| {
-            r.x = ((r.x as isize + r.vx).rem_euclid(self.w as isize)) as usize;
-            r.y = ((r.y as isize + r.vy).rem_euclid(self.h as isize)) as usize;
-        })
-    }
-
-    fn interesting(&self) -> bool {
-        // No overlaps
-        let mut set = HashSet::new();
-
-        for r in &self.robots {
-            if !set.insert((r.x, r.y)) {
-                return false;
-            }
-        }
-
-        true
-    }
-}
-
-// Input parsing
-
-type InputEnt = Robot;
-
-static RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap());
-
-fn input_transform(line: &str) -> InputEnt {
-    let c: [&str; 4] = RE
-        .captures(line)
-        .map(|c| c.extract())
-        .map(|(_, arr)| arr)
-        .expect("Pattern not found");
-
-    Robot {
-        x: c[0].parse::<usize>().unwrap(),
-        y: c[1].parse::<usize>().unwrap(),
-        vx: c[2].parse::<isize>().unwrap(),
-        vy: c[3].parse::<isize>().unwrap(),
-    }
-}
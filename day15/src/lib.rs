@@ -0,0 +1,224 @@
+use std::error::Error;
+
+use aoc::{
+    geom::Dir4,
+    grid::Grid,
+    input::sections,
+    observer::Observer,
+    solution::{Answer, Solution},
+};
+
+/// Day 15 solution
+#[derive(Clone)]
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: usize = 15;
+
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(input.into())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
+
+/// Sums the GPS coordinates of the boxes after all moves
+pub fn part1(input: &str) -> u64 {
+    let (mut map, moves) = parse_input_str(input, false);
+
+    make_moves(&mut map, moves, &mut ());
+    calc_gps(&map, Item::Box)
+}
+
+/// Sums the GPS coordinates of the boxes after all moves in the doubled warehouse
+pub fn part2(input: &str) -> u64 {
+    let (mut map, moves) = parse_input_str(input, true);
+
+    make_moves(&mut map, moves, &mut ());
+    calc_gps(&map, Item::BoxL)
+}
+
+/// Events emitted while moving the robot
+pub enum Event<'a> {
+    /// The robot moved, pushing boxes from and to the given positions
+    Moved {
+        map: &'a Map,
+        pushed: &'a [(Coord, Coord)],
+    },
+}
+
+/// Moves the robot around the map
+pub fn make_moves<O>(map: &mut Map, moves: Vec<Dir4>, observer: &mut O)
+where
+    O: for<'a> Observer<Event<'a>>,
+{
+    for m in moves {
+        let Some(robot_next) = map.grid.offset(map.robot, m.offset()) else {
+            continue;
+        };
+
+        let mut next_moves = Vec::new();
+
+        if check_move(map, m, robot_next, &mut next_moves) {
+            apply_moves(map, &next_moves);
+
+            map.robot = robot_next;
+
+            observer.event(Event::Moved {
+                map,
+                pushed: &next_moves,
+            });
+        }
+    }
+}
+
+fn check_move(map: &Map, m: Dir4, from: Coord, next_moves: &mut Vec<(Coord, Coord)>) -> bool {
+    let updown = m == Dir4::N || m == Dir4::S;
+
+    let mut check_next: Vec<(Coord, Coord)> = Vec::new();
+
+    let item = map.grid[from];
+
+    if item == Some(Item::Wall) {
+        // Move not possible
+        return false;
+    }
+
+    if item.is_some() {
+        let Some(to) = map.grid.offset(from, m.offset()) else {
+            return false;
+        };
+
+        // Moving a wide box up or down also moves its other half
+        let other = match item {
+            Some(Item::BoxL) => Some(Dir4::E),
+            Some(Item::BoxR) => Some(Dir4::W),
+            _ => None,
+        };
+
+        if let Some(other) = other.filter(|_| updown) {
+            let offset = other.offset();
+            check_next.push((
+                from.checked_add(offset).unwrap(),
+                to.checked_add(offset).unwrap(),
+            ));
+        }
+
+        check_next.push((from, to));
+    }
+
+    if check_next.is_empty() {
+        true
+    } else {
+        check_next.iter().all(|ent| {
+            if !next_moves.contains(ent) {
+                if check_move(map, m, ent.1, next_moves) {
+                    next_moves.push(*ent);
+                    true
+                } else {
+                    false
+                }
+            } else {
+                true
+            }
+        })
+    }
+}
+
+fn apply_moves(map: &mut Map, moves: &[(Coord, Coord)]) {
+    for &(from, to) in moves {
+        map.grid[to] = map.grid[from].take();
+    }
+}
+
+fn calc_gps(map: &Map, item: Item) -> u64 {
+    map.grid
+        .iter()
+        .filter(|&(_, i)| *i == Some(item))
+        .map(|(pos, _)| (100 * pos.y) + pos.x)
+        .sum::<usize>() as u64
+}
+
+pub type Coord = aoc::geom::Coord<usize>;
+
+/// Map item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Item {
+    Wall,
+    Box,
+    BoxL,
+    BoxR,
+}
+
+/// Warehouse map
+pub struct Map {
+    pub grid: Grid<Option<Item>>,
+    pub robot: Coord,
+}
+
+// Input parsing
+
+/// Parses the map and robot moves, doubling the width of the map for part 2
+pub fn parse_input_str(input: &str, double: bool) -> (Map, Vec<Dir4>) {
+    let mut sections = sections(input);
+
+    let map = sections.next().unwrap();
+
+    let mut robot = Coord::default();
+
+    let rows = map
+        .lines()
+        .enumerate()
+        .map(|(y, l)| {
+            l.chars()
+                .enumerate()
+                .flat_map(|(x, c)| {
+                    if c == '@' {
+                        robot = Coord::new(if double { x * 2 } else { x }, y);
+                    }
+
+                    let items = match c {
+                        '#' => [Some(Item::Wall), Some(Item::Wall)],
+                        'O' if double => [Some(Item::BoxL), Some(Item::BoxR)],
+                        'O' => [Some(Item::Box), None],
+                        _ => [None, None],
+                    };
+
+                    items.into_iter().take(if double { 2 } else { 1 })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let map = Map {
+        grid: Grid::from_rows(rows),
+        robot,
+    };
+
+    let moves = sections
+        .next()
+        .unwrap()
+        .as_str()
+        .chars()
+        .filter_map(|c| match c {
+            '^' => Some(Dir4::N),
+            '>' => Some(Dir4::E),
+            'v' => Some(Dir4::S),
+            '<' => Some(Dir4::W),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    (map, moves)
+}
+
+#[cfg(test)]
+mod tests;
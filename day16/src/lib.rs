@@ -0,0 +1,297 @@
+use std::error::Error;
+
+use aoc::{
+    geom::Dir4,
+    grid::Grid,
+    observer::Observer,
+    search,
+    solution::{Answer, Solution},
+};
+use fxhash::{FxHashMap, FxHashSet};
+
+/// Day 16 solution
+#[derive(Clone)]
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: usize = 16;
+
+    type Input = (Graph, u64, Vec<usize>);
+
+    const SHARED_PARSE: bool = true;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let graph = build_graph(&Grid::parse_try(input, input_transform)?)?;
+        let (best_score, best_edges) = walk(&graph, &mut ()).ok_or("No route from S to E")?;
+
+        Ok((graph, best_score, best_edges))
+    }
+
+    fn part1(&self, (_, best_score, _): &Self::Input) -> Answer {
+        (*best_score).into()
+    }
+
+    fn part2(&self, (graph, _, best_edges): &Self::Input) -> Option<Answer> {
+        Some(part2(graph, best_edges).into())
+    }
+}
+
+/// Counts the tiles on any of the best routes
+pub fn part2(graph: &Graph, best_edges: &[usize]) -> u64 {
+    // Build hashset of all coordinates in best paths
+    let coords = best_edges
+        .iter()
+        .flat_map(|e| graph.edges[*e].path.iter().copied())
+        .collect::<FxHashSet<Coord>>();
+
+    coords.len() as u64
+}
+
+/// Events emitted while searching the graph
+pub enum Event {
+    /// Edges leading on from an edge are being explored. The edge is None at the start
+    Visited(Option<usize>),
+    /// An edge was queued to walk next with the score for walking it
+    Queued {
+        from: Option<usize>,
+        edge: usize,
+        score: u64,
+    },
+}
+
+/// Finds the best score through the maze and the edges on all routes with that score, or
+/// None if the end can't be reached
+pub fn walk<O>(graph: &Graph, observer: &mut O) -> Option<(u64, Vec<usize>)>
+where
+    O: Observer<Event>,
+{
+    // Search state is the last edge walked, or None at the start
+    let node_dir = |state: &Option<usize>| match state {
+        None => (graph.start, Dir4::E),
+        Some(e) => (graph.edges[*e].tonode, graph.edges[*e].outdir),
+    };
+
+    let result = search::dijkstra_all(
+        None,
+        |&state| {
+            let (node, dir) = node_dir(&state);
+
+            observer.event(Event::Visited(state));
+
+            // Collect the edges to walk next so they can be reported to the observer
+            let mut next = Vec::with_capacity(3);
+
+            for en in graph.nodes[node].edges.iter() {
+                let edge = &graph.edges[*en];
+
+                // Don't double back
+                if dir.opposite() == edge.indir {
+                    continue;
+                }
+
+                // Calculate edge score
+                let mut score = edge.score;
+
+                if dir != edge.indir {
+                    // Turn needed to enter the edge
+                    score += 1000;
+                }
+
+                observer.event(Event::Queued {
+                    from: state,
+                    edge: *en,
+                    score,
+                });
+
+                next.push((Some(*en), score));
+            }
+
+            next
+        },
+        |state| node_dir(state).0 == graph.end,
+    );
+
+    let cost = result.cost()?;
+
+    // Collect the edges on all of the best routes
+    let best_edges = result.optimal_states().into_iter().flatten().collect();
+
+    Some((cost, best_edges))
+}
+
+/// Graph of junctions in the maze joined by corridor edges
+pub struct Graph {
+    pub start: usize,
+    pub end: usize,
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+}
+
+/// Junction in the maze
+pub struct Node {
+    pub pos: Coord,
+    pub edges: Vec<usize>,
+}
+
+/// Corridor leading from a junction
+pub struct Edge {
+    pub tonode: usize,
+    pub indir: Dir4,
+    pub outdir: Dir4,
+    pub score: u64,
+    pub path: Vec<Coord>,
+}
+
+/// Builds the junction graph from the map. Returns an error if the map has no start or end
+pub fn build_graph(grid: &Grid<MapTile>) -> Result<Graph, aoc::Error> {
+    // Find start and end
+    let spos = grid
+        .find_tile(&MapTile::Start)
+        .ok_or(aoc::Error::parse("No start tile (S) in the map"))?;
+    let epos = grid
+        .find_tile(&MapTile::End)
+        .ok_or(aoc::Error::parse("No end tile (E) in the map"))?;
+
+    // Find nodes
+    let mut nodes = Vec::new();
+
+    for (pos, t) in grid.iter() {
+        if *t == MapTile::Wall {
+            // Skip wall tiles
+            continue;
+        }
+
+        // Get directions from this tile
+        let dirs = dirs(grid, pos, None);
+
+        // Is a node if more than 2 outward directions or start or end position
+        if dirs.count() > 2 || pos == spos || pos == epos {
+            nodes.push(Node {
+                pos,
+                edges: Vec::new(),
+            });
+        }
+    }
+
+    // Build coordinate to node map
+    let node_map = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (n.pos, i))
+        .collect::<FxHashMap<_, _>>();
+
+    // Build edges
+    let mut edges = Vec::new();
+
+    for n in nodes.iter_mut() {
+        // Loop outward directions from this node
+        for (dir, mut next) in dirs(grid, n.pos, None) {
+            let mut cur_dir = dir;
+            let mut path = Vec::new();
+            let mut score = 1;
+
+            // Add node to path
+            path.push(n.pos);
+
+            loop {
+                // Add next position to the path
+                path.push(next);
+
+                // Get next direction and position from current without backtracking
+                if let Some((next_dir, next_pos)) =
+                    dirs(grid, next, Some(cur_dir.opposite())).next()
+                {
+                    // Arrived at a node?
+                    if let Some(n2) = node_map.get(&next) {
+                        // Yes - add the edge
+                        n.edges.push(edges.len());
+
+                        edges.push(Edge {
+                            tonode: *n2,
+                            indir: dir,
+                            outdir: cur_dir,
+                            score,
+                            path,
+                        });
+
+                        break;
+                    }
+
+                    // Update edge score and direction
+                    score += 1;
+
+                    if next_dir != cur_dir {
+                        score += 1000;
+                        cur_dir = next_dir;
+                    }
+
+                    // Set new position
+                    next = next_pos;
+                } else {
+                    // Dead end
+                    break;
+                }
+            }
+        }
+    }
+
+    // Get start and end nodes. These are always nodes
+    let start = node_map[&spos];
+    let end = node_map[&epos];
+
+    Ok(Graph {
+        start,
+        end,
+        nodes,
+        edges,
+    })
+}
+
+pub type Coord = aoc::geom::Coord<usize>;
+
+fn dirs(
+    grid: &Grid<MapTile>,
+    c: Coord,
+    skip_dir: Option<Dir4>,
+) -> impl Iterator<Item = (Dir4, Coord)> {
+    Dir4::ALL.into_iter().filter_map(move |mdir| {
+        if let Some(skip_dir) = skip_dir
+            && mdir == skip_dir
+        {
+            return None;
+        }
+
+        let next = grid.offset(c, mdir.offset())?;
+
+        if grid[next] == MapTile::Wall {
+            return None;
+        }
+
+        Some((mdir, next))
+    })
+}
+
+// Input parsing
+
+/// Map tile
+#[derive(PartialEq)]
+pub enum MapTile {
+    Empty,
+    Wall,
+    Start,
+    End,
+}
+
+/// Parses a map tile
+pub fn input_transform(c: char) -> Result<MapTile, String> {
+    match c {
+        '.' => Ok(MapTile::Empty),
+        '#' => Ok(MapTile::Wall),
+        'S' => Ok(MapTile::Start),
+        'E' => Ok(MapTile::End),
+        _ => Err(format!("Bad map tile '{c}'")),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -1,5 +1,3 @@
-use aoc::input::parse_test_vec;
-
 use super::*;
 
 const EXAMPLE1: &str = "\
@@ -42,20 +40,57 @@ const EXAMPLE2: &str = "\
 
 #[test]
 fn test1() {
-    let input = parse_test_vec(EXAMPLE1, input_transform).unwrap();
-    let graph = build_graph(&input);
-    let (best_score, best_routes) = walk(&graph);
+    let input = Grid::parse_try(EXAMPLE1, input_transform).unwrap();
+    let graph = build_graph(&input).unwrap();
+    let (best_score, best_edges) = walk(&graph, &mut ()).unwrap();
 
     assert_eq!(best_score, 7036);
-    assert_eq!(part2(&graph, &best_routes), 45);
+    assert_eq!(part2(&graph, &best_edges), 45);
 }
 
 #[test]
 fn test2() {
-    let input = parse_test_vec(EXAMPLE2, input_transform).unwrap();
-    let graph = build_graph(&input);
-    let (best_score, best_routes) = walk(&graph);
+    let input = Grid::parse_try(EXAMPLE2, input_transform).unwrap();
+    let graph = build_graph(&input).unwrap();
+    let (best_score, best_edges) = walk(&graph, &mut ()).unwrap();
 
     assert_eq!(best_score, 11048);
-    assert_eq!(part2(&graph, &best_routes), 64);
+    assert_eq!(part2(&graph, &best_edges), 64);
+}
+
+#[test]
+fn test_no_route() {
+    let input = "\
+#######
+#S.#.E#
+#######
+";
+
+    let err = Day16.parse(input).err().unwrap();
+
+    assert_eq!(err.to_string(), "No route from S to E");
+}
+
+#[test]
+fn test_parse_errors() {
+    let err = |input| Day16.parse(input).err().unwrap().to_string();
+
+    assert_eq!(
+        err("#####\n#S.x#\n#####\n"),
+        "Parse error at line 2, column 4: Bad map tile 'x'"
+    );
+    assert_eq!(
+        err("#####\n#S..#\n#####\n"),
+        "Parse error: No end tile (E) in the map"
+    );
+    assert_eq!(
+        err("#####\n#..E#\n#####\n"),
+        "Parse error: No start tile (S) in the map"
+    );
+
+    // Maps without a wall border don't walk off the edge
+    assert_eq!(
+        Day16.part1(&Day16.parse("S..\n.#.\n..E\n").unwrap()),
+        Answer::Int(1004)
+    );
 }
@@ -1,214 +1,199 @@
 use std::{
-    cmp::Ordering,
-    collections::{BinaryHeap, HashMap},
+    collections::{HashMap, VecDeque},
     error::Error,
 };
 
-use aoc::{gif::Gif, input::parse_input_vec};
+use aoc::{
+    gif::{Canvas, CaptionBar, Colour, Gif, Palette, Ramp},
+    grid::Grid,
+    vis::Options,
+};
+use day16::{Coord, Event, Graph, MapTile, build_graph, input_transform};
 
 fn main() -> Result<(), Box<dyn Error>> {
+    // Get options
+    let options = Options::from_args()?;
+
     // Get input
-    let input = parse_input_vec(16, input_transform)?;
-    let graph = build_graph(&input);
-    walk(&input, &graph, "vis/day16.gif")?;
+    let input = Grid::from_input_try(16, input_transform)?;
+    let graph = build_graph(&input)?;
+    walk(&input, &graph, &options, "vis/day16.gif")?;
 
     Ok(())
 }
 
-#[derive(PartialEq, Eq)]
-struct Work {
-    node: usize,
-    dir: Dir,
-    score: u64,
-    dist: usize,
-    route: Vec<usize>,
+const SCALE: u16 = 4;
+
+/// Search progress built from the solver's events
+#[derive(Default)]
+struct Progress {
+    /// Best score and previous edge for each edge queued
+    routes: HashMap<usize, (u64, Option<usize>)>,
+    /// Most recently queued edges
+    recent: VecDeque<usize>,
+    /// Number of search states visited
+    visited: usize,
+    /// Best score to the end so far
+    best: Option<u64>,
 }
 
-impl Ord for Work {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other
-            .score
-            .cmp(&self.score)
-            .then_with(|| other.dist.cmp(&self.dist))
+impl Progress {
+    /// Returns the best score so far to the end of an edge
+    fn score(&self, edge: Option<usize>) -> u64 {
+        edge.map_or(0, |e| self.routes[&e].0)
     }
-}
 
-impl PartialOrd for Work {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    /// Returns the edges on the best route so far to the end of an edge
+    fn route(&self, edge: usize) -> Vec<usize> {
+        std::iter::successors(Some(edge), |e| self.routes[e].1).collect()
     }
 }
 
-const SCALE: usize = 4;
+/// Palette colours
+struct Colours {
+    wall: Colour,
+    node: Colour,
+    start: Colour,
+    end: Colour,
+    /// White fading to grey
+    route: Ramp,
+}
 
-fn walk(input: &[InputEnt], graph: &Graph, file: &str) -> Result<(), Box<dyn Error>> {
+fn walk(
+    input: &Grid<MapTile>,
+    graph: &Graph,
+    options: &Options,
+    file: &str,
+) -> Result<(), Box<dyn Error>> {
     // Build palette
-    let mut palette = vec![[0, 0, 0], [128, 128, 255], [80, 80, 0], [255, 0, 0], [
-        0, 255, 0,
-    ]];
-
-    for i in 0..25 {
-        let c = 255 - (i * 8);
-        palette.push([c, c, c]);
-    }
-
-    // Create GIF
-    let mut gif = Gif::new(
+    let mut palette = Palette::new();
+    let black = palette.add([0, 0, 0])?;
+
+    let colours = Colours {
+        wall: palette.add([128, 128, 255])?,
+        node: palette.add([80, 80, 0])?,
+        start: palette.add([255, 0, 0])?,
+        end: palette.add([0, 255, 0])?,
+        route: palette.ramp([255, 255, 255], [63, 63, 63], 25)?,
+    };
+
+    // Create GIF with a status bar for the score
+    let caption = CaptionBar {
+        scale: 2,
+        ..CaptionBar::new(1, colours.route.get(0), black)
+    };
+
+    let mut gif = options.gif_with_caption(
         file,
-        &palette,
-        graph.maxx as u16,
-        graph.maxy as u16,
-        SCALE as u16,
-        SCALE as u16,
+        palette.colours(),
+        input.width() as u16,
+        input.height() as u16,
+        SCALE,
+        caption,
     )?;
 
-    let mut frameno = 0;
-
-    let mut best_score = u64::MAX;
-    let mut best_routes = Vec::new();
-
-    let mut scores = HashMap::new();
-
-    let mut workq = BinaryHeap::new();
-
-    // Add start point to work queue
-    workq.push(Work {
-        node: graph.start,
-        dir: Dir::E,
-        score: 0,
-        dist: graph.nodes[graph.start].dist,
-        route: Vec::new(),
-    });
-
-    // Process work queue
-    while let Some(work) = workq.pop() {
-        if work.node == graph.end {
-            // At the end node - compare best score
-            match work.score.cmp(&best_score) {
-                Ordering::Less => {
-                    // New best score
-                    best_score = work.score;
-                    best_routes = vec![work.route];
-                }
-                Ordering::Equal => {
-                    // Equal best score
-                    best_routes.push(work.route);
-                }
-                Ordering::Greater => (),
-            }
-
-            continue;
-        }
+    // Changes are often small and far apart
+    gif.set_split_regions(true);
+    gif.set_transparent_deltas(true);
 
-        // Visited from this direction before?
-        if let Some(score) = scores.get_mut(&(work.node, work.dir)) {
-            // Yes - is the score worse?
-            if *score < work.score {
-                // Yes - ignore
-                continue;
-            }
+    let frame_skip = options.frame_skip(12);
+    let delay = options.delay(2);
 
-            // No - new best score for this node from this direction
-            *score = work.score;
-        } else {
-            // First time visited in this direction
-            scores.insert((work.node, work.dir), work.score);
-        }
+    let mut progress = Progress::default();
+    let mut result = Ok(());
 
-        // Iterate node edges
-        for en in graph.nodes[work.node].edges.iter() {
-            let edge = &graph.edges[*en];
+    let (best_score, best_edges) = day16::walk(graph, &mut |event| match event {
+        Event::Visited(_) => {
+            progress.visited += 1;
 
-            // Don't double back
-            if work.dir.opposite() == edge.indir {
-                continue;
+            if result.is_ok() && progress.visited % frame_skip == 0 {
+                result = draw_progress(&mut gif, &colours, input, graph, &progress, delay);
             }
-
-            // Calculate new score
-            let mut score = work.score + edge.score;
-
-            if work.dir != edge.indir {
-                // Turn needed to enter the edge
-                score += 1000;
+        }
+        Event::Queued { from, edge, score } => {
+            let score = progress.score(from) + score;
+
+            // Record the route to the edge if it is the best so far
+            if progress
+                .routes
+                .get(&edge)
+                .is_none_or(|(best, _)| score < *best)
+            {
+                progress.routes.insert(edge, (score, from));
             }
 
-            // Check current score is not more than the best score
-            if score > best_score {
-                continue;
+            // Keep the best score to the end
+            if graph.edges[edge].tonode == graph.end {
+                progress.best = Some(progress.best.map_or(score, |best| best.min(score)));
             }
 
-            // Build new route
-            let mut new_route = work.route.clone();
-
-            new_route.push(*en);
-
-            // Add work queue element
-            workq.push(Work {
-                node: edge.tonode,
-                dir: edge.outdir,
-                score,
-                dist: graph.nodes[work.node].dist,
-                route: new_route,
-            });
+            progress.recent.push_front(edge);
+            progress.recent.truncate(25);
         }
+    })
+    .ok_or("No route from S to E")?;
 
-        frameno += 1;
-        if frameno % 12 == 0 {
-            draw_workq(&mut gif, input, graph, &workq)?;
-        }
-    }
+    result?;
 
-    draw_best(&mut gif, input, graph, &best_routes)?;
+    gif.set_caption(&format!("Best score: {best_score}"));
+
+    draw_best(&mut gif, &colours, input, graph, &best_edges, delay)?;
 
     gif.delay(1000)?;
 
     Ok(())
 }
 
-fn draw_workq(
+fn draw_progress(
     gif: &mut Gif,
-    input: &[Vec<MapTile>],
+    colours: &Colours,
+    input: &Grid<MapTile>,
     graph: &Graph,
-    workq: &BinaryHeap<Work>,
+    progress: &Progress,
+    delay: u16,
 ) -> Result<(), Box<dyn Error>> {
-    let mut frame = gif.empty_frame();
+    let mut frame = gif.empty_canvas();
 
     // Draw map
-    draw_map(&mut frame, input, graph);
-
-    let draw = workq.len().min(25);
+    draw_map(&mut frame, colours, input, graph);
 
-    for (i, work) in workq.iter().enumerate().take(draw).rev() {
-        for en in &work.route {
-            let edge = &graph.edges[*en];
+    for (i, edge) in progress.recent.iter().enumerate().rev() {
+        for en in progress.route(*edge) {
+            let edge = &graph.edges[en];
 
-            for &(x, y) in &edge.path {
-                frame[y][x] = (i + 5) as u8;
+            for &Coord { x, y } in &edge.path {
+                frame[(x, y)] = colours.route.get(i).into();
             }
         }
     }
 
-    draw_startend(&mut frame, graph);
+    draw_startend(&mut frame, colours, graph);
 
-    gif.draw_frame(frame, 2)?;
+    // Show the best score so far
+    match progress.best {
+        Some(best) => gif.set_caption(&format!("Best score: {best}")),
+        None => gif.set_caption("Best score: -"),
+    }
+
+    gif.draw_frame(frame, delay)?;
 
     Ok(())
 }
 
 fn draw_best(
     gif: &mut Gif,
-    input: &[Vec<MapTile>],
+    colours: &Colours,
+    input: &Grid<MapTile>,
     graph: &Graph,
-    best: &Vec<Vec<usize>>,
+    best: &[usize],
+    delay: u16,
 ) -> Result<(), Box<dyn Error>> {
-    let mut counts: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut counts: HashMap<Coord, usize> = HashMap::new();
 
     // Get visit counts for each location
-    for r in best {
-        for edge in r {
-            for &c in graph.edges[*edge].path.iter().skip(1) {
-                counts.entry(c).and_modify(|e| *e += 1).or_insert(1);
-            }
+    for edge in best {
+        for &c in graph.edges[*edge].path.iter().skip(1) {
+            counts.entry(c).and_modify(|e| *e += 1).or_insert(1);
         }
     }
 
@@ -216,282 +201,40 @@ fn draw_best(
     let col_step = 12 / max_count;
 
     // Get empty frame
-    let mut frame = gif.empty_frame();
+    let mut frame = gif.empty_canvas();
 
     // Draw map
-    draw_map(&mut frame, input, graph);
+    draw_map(&mut frame, colours, input, graph);
 
     // Draw best paths
-    for ((x, y), c) in counts {
-        frame[y][x] = (((max_count - c) * col_step) + 5) as u8;
+    for (Coord { x, y }, c) in counts {
+        frame[(x, y)] = colours.route.get((max_count - c) * col_step).into();
     }
 
-    draw_startend(&mut frame, graph);
+    draw_startend(&mut frame, colours, graph);
 
     // Output frame
-    gif.draw_frame(frame, 2)?;
+    gif.draw_frame(frame, delay)?;
 
     Ok(())
 }
 
-fn draw_map(frame: &mut [Vec<u8>], input: &[InputEnt], graph: &Graph) {
+fn draw_map(frame: &mut Canvas, colours: &Colours, input: &Grid<MapTile>, graph: &Graph) {
     // Draw walls
-    for (y, l) in input.iter().enumerate() {
-        for (x, t) in l.iter().enumerate() {
-            if *t == MapTile::Wall {
-                frame[y][x] = 1;
-            }
-        }
+    for Coord { x, y } in input.find_all(&MapTile::Wall) {
+        frame[(x, y)] = colours.wall.into();
     }
 
     // Draw nodes
     for n in &graph.nodes {
-        frame[n.pos.1][n.pos.0] = 2;
+        frame[n.pos.into()] = colours.node.into();
     }
 }
 
-fn draw_startend(frame: &mut [Vec<u8>], graph: &Graph) {
+fn draw_startend(frame: &mut Canvas, colours: &Colours, graph: &Graph) {
     let s = &graph.nodes[graph.start];
-    frame[s.pos.1][s.pos.0] = 3;
+    frame[s.pos.into()] = colours.start.into();
 
     let e = &graph.nodes[graph.end];
-    frame[e.pos.1][e.pos.0] = 4;
-}
-
-struct Graph {
-    start: usize,
-    end: usize,
-    nodes: Vec<Node>,
-    edges: Vec<Edge>,
-    maxx: usize,
-    maxy: usize,
-}
-
-struct Node {
-    pos: Coord,
-    dist: usize,
-    edges: Vec<usize>,
-}
-
-struct Edge {
-    tonode: usize,
-    indir: Dir,
-    outdir: Dir,
-    score: u64,
-    path: Vec<Coord>,
-}
-
-fn build_graph(input: &[InputEnt]) -> Graph {
-    // Find start
-    let spos = input
-        .iter()
-        .enumerate()
-        .find_map(|(y, l)| {
-            l.iter().enumerate().find_map(|(x, t)| {
-                if *t == MapTile::Start {
-                    Some((x, y))
-                } else {
-                    None
-                }
-            })
-        })
-        .unwrap();
-
-    // Find end
-    let epos = input
-        .iter()
-        .enumerate()
-        .find_map(|(y, l)| {
-            l.iter().enumerate().find_map(|(x, t)| {
-                if *t == MapTile::End {
-                    Some((x, y))
-                } else {
-                    None
-                }
-            })
-        })
-        .unwrap();
-
-    // Find nodes
-    let mut nodes = Vec::new();
-
-    let dist = |pos: Coord| -> usize { pos.0.abs_diff(epos.0) + pos.1.abs_diff(epos.1) };
-
-    for (y, l) in input.iter().enumerate() {
-        for (x, t) in l.iter().enumerate() {
-            if *t == MapTile::Wall {
-                // Skip wall tiles
-                continue;
-            }
-
-            // Build position tuple
-            let pos = (x, y);
-
-            // Get directions from this tile
-            let dirs = dirs(input, pos, None);
-
-            // Is a node if more than 2 outward directions or start or end position
-            if dirs.len() > 2 || pos == spos || pos == epos {
-                nodes.push(Node {
-                    pos,
-                    dist: dist(pos),
-                    edges: Vec::new(),
-                });
-            }
-        }
-    }
-
-    // Build coordinate to node map
-    let node_map = nodes
-        .iter()
-        .enumerate()
-        .map(|(i, n)| (n.pos, i))
-        .collect::<HashMap<_, _>>();
-
-    // Build edges
-    let mut edges = Vec::new();
-
-    for n in nodes.iter_mut() {
-        // Loop outward directions from this node
-        for (dir, mut next) in dirs(input, n.pos, None) {
-            let mut cur_dir = dir;
-            let mut path = Vec::new();
-            let mut score = 1;
-
-            // Add node to path
-            path.push(n.pos);
-
-            loop {
-                // Add next position to the path
-                path.push(next);
-
-                // Get next direction and position from current without backtracking
-                let dirs = dirs(input, next, Some(cur_dir.opposite()));
-
-                if dirs.is_empty() {
-                    // Dead end
-                    break;
-                }
-
-                // Arrived at a node?
-                if let Some(n2) = node_map.get(&next) {
-                    // Yes - add the edge
-                    n.edges.push(edges.len());
-
-                    edges.push(Edge {
-                        tonode: *n2,
-                        indir: dir,
-                        outdir: cur_dir,
-                        score,
-                        path,
-                    });
-
-                    break;
-                }
-
-                // Update edge score and direction
-                score += 1;
-
-                if dirs[0].0 != cur_dir {
-                    score += 1000;
-                    cur_dir = dirs[0].0;
-                }
-
-                // Set new position
-                next = dirs[0].1;
-            }
-        }
-    }
-
-    // Get start and end nodes
-    let start = *node_map.get(&spos).unwrap();
-    let end = *node_map.get(&epos).unwrap();
-
-    Graph {
-        start,
-        end,
-        nodes,
-        edges,
-        maxx: input[0].len(),
-        maxy: input.len(),
-    }
-}
-
-type Coord = (usize, usize);
-
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-enum Dir {
-    N,
-    E,
-    S,
-    W,
-}
-
-impl Dir {
-    /// Returns the opposite direction
-    fn opposite(&self) -> Self {
-        match self {
-            Dir::N => Dir::S,
-            Dir::E => Dir::W,
-            Dir::S => Dir::N,
-            Dir::W => Dir::E,
-        }
-    }
-}
-
-const DIRS: [(Dir, [isize; 2]); 4] = [
-    (Dir::N, [0, -1]),
-    (Dir::E, [1, 0]),
-    (Dir::S, [0, 1]),
-    (Dir::W, [-1, 0]),
-];
-
-fn dirs(input: &[InputEnt], c: Coord, skip_dir: Option<Dir>) -> Vec<(Dir, Coord)> {
-    DIRS.iter()
-        .filter_map(move |&(mdir, [dx, dy])| match c.0.checked_add_signed(dx) {
-            Some(nx) if nx < input[0].len() => match c.1.checked_add_signed(dy) {
-                Some(ny) if ny < input.len() => {
-                    if input[ny][nx] != MapTile::Wall {
-                        if let Some(skip_dir) = skip_dir {
-                            if mdir == skip_dir {
-                                None
-                            } else {
-                                Some((mdir, (nx, ny)))
-                            }
-                        } else {
-                            Some((mdir, (nx, ny)))
-                        }
-                    } else {
-                        None
-                    }
-                }
-                _ => None,
-            },
-            _ => None,
-        })
-        .collect()
-}
-
-// Input parsing
-
-#[derive(PartialEq)]
-enum MapTile {
-    Empty,
-    Wall,
-    Start,
-    End,
-}
-
-type InputEnt = Vec<MapTile>;
-
-fn input_transform(line: &str) -> InputEnt {
-    line.chars()
-        .map(|c| match c {
-            '.' => MapTile::Empty,
-            '#' => MapTile::Wall,
-            'S' => MapTile::Start,
-            'E' => MapTile::End,
-            _ => panic!("Bad map tile"),
-        })
-        .collect()
+    frame[e.pos.into()] = colours.end.into();
 }
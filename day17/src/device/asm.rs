@@ -0,0 +1,170 @@
+use std::fmt;
+
+use super::DeviceError;
+
+/// Device opcodes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    /// a = a / 2^combo
+    Adv = 0,
+    /// b ^= literal
+    Bxl = 1,
+    /// b = combo % 8
+    Bst = 2,
+    /// Jump to literal if a is not zero
+    Jnz = 3,
+    /// b ^= c, the operand is ignored
+    Bxc = 4,
+    /// Output combo % 8
+    Out = 5,
+    /// b = a / 2^combo
+    Bdv = 6,
+    /// c = a / 2^combo
+    Cdv = 7,
+}
+
+const OPCODES: [Opcode; 8] = [
+    Opcode::Adv,
+    Opcode::Bxl,
+    Opcode::Bst,
+    Opcode::Jnz,
+    Opcode::Bxc,
+    Opcode::Out,
+    Opcode::Bdv,
+    Opcode::Cdv,
+];
+
+impl Opcode {
+    /// Converts a program value to an opcode
+    pub fn from_u8(val: u8) -> Option<Self> {
+        OPCODES.get(val as usize).copied()
+    }
+
+    /// Looks up an opcode by its mnemonic
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        OPCODES.iter().find(|op| op.mnemonic() == mnemonic).copied()
+    }
+
+    /// Returns the assembler mnemonic
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    /// Returns true if the opcode takes a combo operand
+    pub fn is_combo(&self) -> bool {
+        matches!(
+            self,
+            Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv
+        )
+    }
+}
+
+/// A decoded instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instr {
+    pub opcode: Opcode,
+    pub operand: u8,
+}
+
+impl Instr {
+    /// Decodes the instruction at a position in a program
+    pub fn decode(program: &[u8], pc: usize) -> Result<Self, DeviceError> {
+        let opcode = program[pc];
+        let opcode = Opcode::from_u8(opcode).ok_or(DeviceError::InvalidOpcode { pc, opcode })?;
+
+        let operand = *program
+            .get(pc + 1)
+            .ok_or(DeviceError::MissingOperand { pc })?;
+
+        if operand > 7 || (opcode.is_combo() && operand == 7) {
+            Err(DeviceError::InvalidOperand { pc, operand })?
+        }
+
+        Ok(Self { opcode, operand })
+    }
+
+    /// Encodes the instruction as program values
+    pub fn encode(&self) -> [u8; 2] {
+        [self.opcode as u8, self.operand]
+    }
+}
+
+impl fmt::Display for Instr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mnemonic = self.opcode.mnemonic();
+
+        match self.operand {
+            4..=6 if self.opcode.is_combo() => {
+                write!(f, "{mnemonic} {}", (b'a' + self.operand - 4) as char)
+            }
+            operand => write!(f, "{mnemonic} {operand}"),
+        }
+    }
+}
+
+/// Assembles a program from text with one instruction per line, such as `bst a` or
+/// `jnz 0`. Combo operands can name a register. Blank lines and text after `#` are ignored
+pub fn assemble(text: &str) -> Result<Vec<u8>, aoc::Error> {
+    let mut program = Vec::new();
+
+    for (lineno, line) in text.lines().enumerate() {
+        let err = |message: String| aoc::Error::parse(message).at_line(lineno + 1);
+
+        // Strip comments
+        let line = line.split('#').next().unwrap_or_default().trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut words = line.split_whitespace();
+
+        let mnemonic = words.next().unwrap_or_default();
+
+        let opcode = Opcode::from_mnemonic(&mnemonic.to_lowercase())
+            .ok_or_else(|| err(format!("Unknown instruction '{mnemonic}'")))?;
+
+        // The bxc operand is ignored so can be left out
+        let operand = match (words.next(), opcode) {
+            (None, Opcode::Bxc) => 0,
+            (None, _) => Err(err(format!("Missing operand for {mnemonic}")))?,
+            (Some(reg @ ("a" | "b" | "c")), op) if op.is_combo() => reg.as_bytes()[0] - b'a' + 4,
+            (Some(operand), _) => operand
+                .parse()
+                .map_err(|_| err(format!("Invalid operand '{operand}'")))?,
+        };
+
+        if words.next().is_some() {
+            Err(err(format!("Too many operands for {mnemonic}")))?
+        }
+
+        let instr = [opcode as u8, operand];
+
+        // Check the operand is allowed for the opcode
+        Instr::decode(&instr, 0).map_err(|_| err(format!("Invalid operand '{operand}'")))?;
+
+        program.extend(instr);
+    }
+
+    Ok(program)
+}
+
+/// Disassembles a program to text with one instruction per line
+pub fn disassemble(program: &[u8]) -> Result<String, DeviceError> {
+    let mut text = String::new();
+
+    for pc in (0..program.len()).step_by(2) {
+        text += &format!("{}\n", Instr::decode(program, pc)?);
+    }
+
+    Ok(text)
+}
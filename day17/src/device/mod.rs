@@ -0,0 +1,204 @@
+use std::{collections::BTreeSet, error::Error, fmt};
+
+mod asm;
+
+pub use asm::{Instr, Opcode, assemble, disassemble};
+
+/// Register value type
+pub type RegType = u64;
+
+/// Device emulator. Programs can be traced by calling `step` which returns each
+/// instruction executed
+#[derive(Debug, Default)]
+pub struct Device<'a> {
+    reg: [RegType; 3],
+    pc: usize,
+    program: Option<&'a [u8]>,
+    out: Vec<u8>,
+    breakpoints: BTreeSet<usize>,
+    limit: Option<usize>,
+    steps: usize,
+}
+
+impl<'a> Device<'a> {
+    /// Creates a device with no program and zeroed registers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a register value
+    pub fn reg(mut self, reg: Reg, val: RegType) -> Self {
+        self.reg[reg as usize] = val;
+        self
+    }
+
+    /// Sets the program to run
+    pub fn program(mut self, program: &'a [u8]) -> Self {
+        self.program = Some(program);
+        self
+    }
+
+    /// Sets the maximum number of instructions to execute
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Stops a run before executing the instruction at a program position
+    pub fn set_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.insert(pc);
+    }
+
+    /// Removes a breakpoint
+    pub fn clear_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.remove(&pc);
+    }
+
+    /// Returns a register value
+    pub fn get_reg(&self, reg: Reg) -> RegType {
+        self.reg[reg as usize]
+    }
+
+    /// Returns the program counter
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Returns the number of instructions executed
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Returns the values output so far
+    pub fn get_output(&self) -> &Vec<u8> {
+        &self.out
+    }
+
+    /// Returns true if the program counter is past the end of the program
+    pub fn halted(&self) -> bool {
+        self.pc >= self.program.map_or(0, |p| p.len())
+    }
+
+    /// Runs until the program halts or a breakpoint is reached. A run started on a
+    /// breakpoint executes that instruction before checking breakpoints
+    pub fn run(&mut self) -> Result<Stop, DeviceError> {
+        let mut first = true;
+
+        loop {
+            if !first && !self.halted() && self.breakpoints.contains(&self.pc) {
+                return Ok(Stop::Breakpoint(self.pc));
+            }
+
+            first = false;
+
+            if self.step()?.is_none() {
+                return Ok(Stop::Halted);
+            }
+        }
+    }
+
+    /// Executes the next instruction, returning it or None if the program has halted
+    pub fn step(&mut self) -> Result<Option<Instr>, DeviceError> {
+        let Some(program) = self.program.filter(|_| !self.halted()) else {
+            return Ok(None);
+        };
+
+        if let Some(limit) = self.limit.filter(|&l| self.steps >= l) {
+            Err(DeviceError::InstructionLimit(limit))?
+        }
+
+        let instr = Instr::decode(program, self.pc)?;
+
+        self.pc += 2;
+        self.steps += 1;
+
+        let operand = instr.operand;
+
+        match instr.opcode {
+            Opcode::Adv => self.reg[Reg::A as usize] = self.div(operand),
+            Opcode::Bxl => self.reg[Reg::B as usize] ^= operand as RegType,
+            Opcode::Bst => self.reg[Reg::B as usize] = self.combo(operand) % 8,
+            Opcode::Jnz => {
+                if self.reg[Reg::A as usize] != 0 {
+                    self.pc = operand as usize;
+                }
+            }
+            Opcode::Bxc => self.reg[Reg::B as usize] ^= self.reg[Reg::C as usize],
+            Opcode::Out => self.out.push((self.combo(operand) % 8) as u8),
+            Opcode::Bdv => self.reg[Reg::B as usize] = self.div(operand),
+            Opcode::Cdv => self.reg[Reg::C as usize] = self.div(operand),
+        }
+
+        Ok(Some(instr))
+    }
+
+    /// Returns the value of a combo operand
+    fn combo(&self, operand: u8) -> RegType {
+        match operand {
+            4..=6 => self.reg[operand as usize - 4],
+            _ => operand as RegType,
+        }
+    }
+
+    /// Returns register A divided by 2 to the power of a combo operand
+    fn div(&self, operand: u8) -> RegType {
+        u32::try_from(self.combo(operand))
+            .ok()
+            .and_then(|shift| self.reg[Reg::A as usize].checked_shr(shift))
+            .unwrap_or(0)
+    }
+}
+
+/// Device registers
+#[derive(Debug, Clone, Copy)]
+pub enum Reg {
+    A = 0,
+    B = 1,
+    C = 2,
+}
+
+/// Reason a run stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The program counter moved past the end of the program
+    Halted,
+    /// A breakpoint was reached at a program position
+    Breakpoint(usize),
+}
+
+/// Errors stopping the device
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceError {
+    /// An opcode above 7
+    InvalidOpcode { pc: usize, opcode: u8 },
+    /// An operand above 7, or combo operand 7
+    InvalidOperand { pc: usize, operand: u8 },
+    /// The last instruction has no operand
+    MissingOperand { pc: usize },
+    /// The instruction limit was reached before the program halted
+    InstructionLimit(usize),
+}
+
+impl fmt::Display for DeviceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeviceError::InvalidOpcode { pc, opcode } => {
+                write!(f, "Invalid opcode {opcode} at position {pc}")
+            }
+            DeviceError::InvalidOperand { pc, operand } => {
+                write!(f, "Invalid operand {operand} at position {pc}")
+            }
+            DeviceError::MissingOperand { pc } => {
+                write!(f, "Missing operand at position {pc}")
+            }
+            DeviceError::InstructionLimit(limit) => {
+                write!(f, "Program did not halt within {limit} instructions")
+            }
+        }
+    }
+}
+
+impl Error for DeviceError {}
+
+#[cfg(test)]
+mod tests;
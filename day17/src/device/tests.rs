@@ -0,0 +1,130 @@
+use super::*;
+
+const PROGRAM: &str = "\
+bst a    # b = a % 8
+bxl 1
+cdv b
+bxc
+bxl 4
+out b
+adv 3
+jnz 0
+";
+
+#[test]
+fn test_assemble() {
+    let program = assemble(PROGRAM).unwrap();
+
+    assert_eq!(
+        program,
+        vec![2, 4, 1, 1, 7, 5, 4, 0, 1, 4, 5, 5, 0, 3, 3, 0]
+    );
+
+    let text = disassemble(&program).unwrap();
+
+    assert_eq!(text.lines().next(), Some("bst a"));
+    assert_eq!(text.lines().nth(3), Some("bxc 0"));
+    assert_eq!(assemble(&text).unwrap(), program);
+}
+
+#[test]
+fn test_assemble_errors() {
+    assert_eq!(
+        assemble("adv 1\nmul 2\n").unwrap_err().to_string(),
+        "Parse error at line 2: Unknown instruction 'mul'"
+    );
+    assert_eq!(
+        assemble("out 7").unwrap_err().to_string(),
+        "Parse error at line 1: Invalid operand '7'"
+    );
+    assert!(assemble("bxl a").is_err());
+    assert!(assemble("jnz").is_err());
+    assert!(assemble("bst a b").is_err());
+}
+
+#[test]
+fn test_decode_errors() {
+    assert_eq!(
+        disassemble(&[0, 3, 5, 7]),
+        Err(DeviceError::InvalidOperand { pc: 2, operand: 7 })
+    );
+    assert_eq!(
+        disassemble(&[8, 0]),
+        Err(DeviceError::InvalidOpcode { pc: 0, opcode: 8 })
+    );
+    assert_eq!(
+        disassemble(&[0, 3, 5]),
+        Err(DeviceError::MissingOperand { pc: 2 })
+    );
+
+    // bxl takes a literal operand so 7 is allowed
+    assert_eq!(disassemble(&[1, 7]).unwrap(), "bxl 7\n");
+
+    let mut device = Device::new().reg(Reg::A, 1).program(&[5, 4, 3, 3]); // out a; jnz 3
+
+    assert_eq!(device.run(), Err(DeviceError::MissingOperand { pc: 3 }));
+    assert_eq!(device.get_output(), &vec![1]);
+}
+
+#[test]
+fn test_step() {
+    let program = [0, 1, 5, 4, 3, 0]; // a /= 2; out a % 8; if a <> 0 loop
+
+    let mut device = Device::new().reg(Reg::A, 20).program(&program);
+
+    let instr = device.step().unwrap().unwrap();
+
+    assert_eq!(instr.opcode, Opcode::Adv);
+    assert_eq!(instr.to_string(), "adv 1");
+    assert_eq!(device.get_reg(Reg::A), 10);
+    assert_eq!(device.pc(), 2);
+
+    while device.step().unwrap().is_some() {}
+
+    assert!(device.halted());
+    assert_eq!(device.get_output(), &vec![2, 5, 2, 1, 0]);
+    assert_eq!(device.steps(), 15);
+    assert_eq!(device.step(), Ok(None));
+}
+
+#[test]
+fn test_breakpoint() {
+    let program = [0, 1, 5, 4, 3, 0];
+
+    let mut device = Device::new().reg(Reg::A, 20).program(&program);
+
+    device.set_breakpoint(2);
+
+    assert_eq!(device.run(), Ok(Stop::Breakpoint(2)));
+    assert_eq!(device.get_reg(Reg::A), 10);
+    assert!(device.get_output().is_empty());
+
+    // Resuming executes the instruction at the breakpoint
+    assert_eq!(device.run(), Ok(Stop::Breakpoint(2)));
+    assert_eq!(device.get_output(), &vec![2]);
+
+    device.clear_breakpoint(2);
+
+    assert_eq!(device.run(), Ok(Stop::Halted));
+    assert_eq!(device.get_output(), &vec![2, 5, 2, 1, 0]);
+}
+
+#[test]
+fn test_limit() {
+    let mut device = Device::new().reg(Reg::A, 1).program(&[3, 0]).limit(100); // loop forever
+
+    assert_eq!(device.run(), Err(DeviceError::InstructionLimit(100)));
+    assert_eq!(device.steps(), 100);
+}
+
+#[test]
+fn test_large_shift() {
+    let mut device = Device::new()
+        .reg(Reg::A, 7)
+        .reg(Reg::B, 64)
+        .program(&[0, 5]); // a /= 2^b
+
+    device.run().unwrap();
+
+    assert_eq!(device.get_reg(Reg::A), 0);
+}
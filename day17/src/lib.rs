@@ -0,0 +1,147 @@
+use std::error::Error;
+
+use aoc::solution::{Answer, Solution};
+use device::{Device, DeviceError, Reg};
+use regex::Regex;
+
+pub mod device;
+
+/// Maximum number of instructions to run before giving up on a program halting
+const LIMIT: usize = 1_000_000;
+
+/// Day 17 solution
+#[derive(Clone)]
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: usize = 17;
+
+    type Input = (u64, Vec<u8>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_input_str(input)?)
+    }
+
+    fn part1(&self, (rega, program): &Self::Input) -> Answer {
+        part1(*rega, program)
+            .unwrap_or_else(|e| format!("Error: {e}"))
+            .into()
+    }
+
+    fn part2(&self, (_, program): &Self::Input) -> Option<Answer> {
+        Some(match part2(program) {
+            Some(rega) => rega.into(),
+            None => "No solution".into(),
+        })
+    }
+}
+
+/// Runs the program returning its comma separated output
+pub fn part1(rega: u64, program: &[u8]) -> Result<String, DeviceError> {
+    let mut device = Device::new()
+        .reg(Reg::A, rega)
+        .program(program)
+        .limit(LIMIT);
+
+    device.run()?;
+
+    let strvals = device
+        .get_output()
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>();
+
+    Ok(strvals.join(","))
+}
+
+/// Finds the lowest register A value which makes the program output itself, or None if
+/// there is no such value. The program must output one value for each 3 bits of register A,
+/// shifting A right by 3 bits each loop
+pub fn part2(program: &[u8]) -> Option<u64> {
+    find_quine(program, 0, program.len())
+}
+
+/// Searches for a register A value by adding 3 bits at a time to the low end of a prefix
+/// value. Each extra 3 bits must output one more value from the end of the program
+fn find_quine(program: &[u8], prefix: u64, remaining: usize) -> Option<u64> {
+    if remaining == 0 {
+        return Some(prefix);
+    }
+
+    let base = prefix.checked_mul(8)?;
+    let expected = &program[remaining - 1..];
+
+    // Try the lowest bits first so the first solution found is the smallest
+    (0..8).find_map(|bits| {
+        let rega = base + bits;
+
+        let mut device = Device::new()
+            .reg(Reg::A, rega)
+            .program(program)
+            .limit(LIMIT);
+
+        match device.run() {
+            Ok(_) if device.get_output() == expected => find_quine(program, rega, remaining - 1),
+            _ => None,
+        }
+    })
+}
+
+// Input parsing
+
+/// Parses the register A value and the program
+pub fn parse_input_str(input: &str) -> Result<(u64, Vec<u8>), aoc::Error> {
+    let prog_re = Regex::new(r"Program: ([\d,]*)").expect("Failed to create regex");
+
+    let prog_match = prog_re
+        .captures(input)
+        .and_then(|c| c.get(1))
+        .ok_or(aoc::Error::parse("Program not found"))?;
+
+    let mut pos = prog_match.start();
+
+    let program = prog_match
+        .as_str()
+        .split(",")
+        .map(|n| {
+            let op = n
+                .parse::<u8>()
+                .map_err(|e| parse_error(input, pos, format!("Invalid program value '{n}': {e}")));
+
+            pos += n.len() + 1;
+
+            op
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    device::disassemble(&program)
+        .map_err(|e| parse_error(input, prog_match.start(), format!("Invalid program: {e}")))?;
+
+    let rega_re = Regex::new(r"Register A: (\d*)").expect("Failed to create regex");
+
+    let rega_match = rega_re
+        .captures(input)
+        .and_then(|c| c.get(1))
+        .ok_or(aoc::Error::parse("Register A not found"))?;
+
+    let reg_a = rega_match.as_str().parse::<u64>().map_err(|e| {
+        parse_error(
+            input,
+            rega_match.start(),
+            format!("Invalid register A value '{}': {e}", rega_match.as_str()),
+        )
+    })?;
+
+    Ok((reg_a, program))
+}
+
+/// Builds a parse error located at a byte position in the input
+fn parse_error(input: &str, pos: usize, message: String) -> aoc::Error {
+    let before = &input[..pos];
+    let line_start = before.rfind('\n').map_or(0, |p| p + 1);
+
+    aoc::Error::parse_at(pos - line_start + 1, message).at_line(before.matches('\n').count() + 1)
+}
+
+#[cfg(test)]
+mod tests;
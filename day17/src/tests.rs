@@ -2,33 +2,27 @@ use super::*;
 
 #[test]
 fn test1() {
-    let mut device = Device::new().debug(true).reg(Reg::C, 9).program(&[2, 6]); // b = c % 8
+    let mut device = Device::new().reg(Reg::C, 9).program(&[2, 6]); // b = c % 8
 
-    device.run();
+    device.run().unwrap();
 
     assert_eq!(device.get_reg(Reg::B), 1);
 }
 
 #[test]
 fn test2() {
-    let mut device = Device::new()
-        .debug(true)
-        .reg(Reg::A, 10)
-        .program(&[5, 0, 5, 1, 5, 4]); // out 0 % 8; out 1 % 8; out a % 8
+    let mut device = Device::new().reg(Reg::A, 10).program(&[5, 0, 5, 1, 5, 4]); // out 0 % 8; out 1 % 8; out a % 8
 
-    device.run();
+    device.run().unwrap();
 
     assert_eq!(device.get_output(), &vec![0, 1, 2]);
 }
 
 #[test]
 fn test3() {
-    let mut device = Device::new()
-        .debug(true)
-        .reg(Reg::A, 2024)
-        .program(&[0, 1, 5, 4, 3, 0]); // a /= 2; out a % 8; if a <> 0 loop
+    let mut device = Device::new().reg(Reg::A, 2024).program(&[0, 1, 5, 4, 3, 0]); // a /= 2; out a % 8; if a <> 0 loop
 
-    device.run();
+    device.run().unwrap();
 
     assert_eq!(device.get_output(), &vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
     assert_eq!(device.get_reg(Reg::A), 0);
@@ -36,9 +30,9 @@ fn test3() {
 
 #[test]
 fn test4() {
-    let mut device = Device::new().debug(true).reg(Reg::B, 29).program(&[1, 7]); // b ^= 7
+    let mut device = Device::new().reg(Reg::B, 29).program(&[1, 7]); // b ^= 7
 
-    device.run();
+    device.run().unwrap();
 
     assert_eq!(device.get_reg(Reg::B), 26);
 }
@@ -46,24 +40,20 @@ fn test4() {
 #[test]
 fn test5() {
     let mut device = Device::new()
-        .debug(true)
         .reg(Reg::B, 2024)
         .reg(Reg::C, 43690)
         .program(&[4, 0]); // b ^= c
 
-    device.run();
+    device.run().unwrap();
 
     assert_eq!(device.get_reg(Reg::B), 44354);
 }
 
 #[test]
 fn test6() {
-    let mut device = Device::new()
-        .debug(true)
-        .reg(Reg::A, 729)
-        .program(&[0, 1, 5, 4, 3, 0]); // a /= 2; out a % 8; if a <> 0 loop
+    let mut device = Device::new().reg(Reg::A, 729).program(&[0, 1, 5, 4, 3, 0]); // a /= 2; out a % 8; if a <> 0 loop
 
-    device.run();
+    device.run().unwrap();
 
     assert_eq!(device.get_output(), &vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
 }
@@ -79,9 +69,9 @@ Program: 0,1,5,4,3,0
 
 #[test]
 fn test7() {
-    let (rega, program) = parse_input_str(EXAMPLE1);
+    let (rega, program) = parse_input_str(EXAMPLE1).unwrap();
 
-    assert_eq!("4,6,3,5,6,3,5,2,1,0", part1(rega, &program));
+    assert_eq!("4,6,3,5,6,3,5,2,1,0", part1(rega, &program).unwrap());
 }
 
 // a /= 8; out a % 8; if a <> 0 loop
@@ -95,14 +85,58 @@ Program: 0,3,5,4,3,0
 
 #[test]
 fn test8() {
-    let (_, program) = parse_input_str(EXAMPLE3);
+    let (_, program) = parse_input_str(EXAMPLE3).unwrap();
 
     let mut device = Device::new()
-        .debug(true)
         .reg(Reg::A, 0o345300 /* 117440 */)
         .program(&program);
 
-    device.run();
+    device.run().unwrap();
 
     assert_eq!(device.get_output(), &program);
 }
+
+#[test]
+fn test_part2() {
+    let (_, program) = parse_input_str(EXAMPLE3).unwrap();
+
+    assert_eq!(part2(&program), Some(0o345300));
+
+    // Needs backtracking as the first 3 bits to match the last value are a dead end
+    let program = device::assemble("bst a\nbxl 2\ncdv b\nbxc\nbxl 3\nout b\nadv 3\njnz 0").unwrap();
+
+    let rega = part2(&program).unwrap();
+
+    assert_eq!(rega, 0o1035510015136764);
+    assert_eq!(
+        part1(rega, &program).unwrap(),
+        "2,4,1,2,7,5,4,0,1,3,5,5,0,3,3,0"
+    );
+
+    let program = device::assemble("bst a\nbxl 1\ncdv b\nbxc\nbxl 4\nout b\nadv 3\njnz 0").unwrap();
+
+    assert_eq!(part2(&program), None);
+}
+
+#[test]
+fn test_parse_error() {
+    let err = parse_input_str("Register A: 729\n\nProgram: 0,1,,4\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parse error at line 3, column 14: Invalid program value '': cannot parse integer from empty string"
+    );
+
+    let err = parse_input_str("Register A: 729\n").unwrap_err();
+    assert_eq!(err.to_string(), "Parse error: Program not found");
+
+    let err = parse_input_str("Register A: \n\nProgram: 0,1\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parse error at line 1, column 13: Invalid register A value '': cannot parse integer from empty string"
+    );
+    let err = parse_input_str("Register A: 729\n\nProgram: 0,7\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parse error at line 3, column 10: Invalid program: Invalid operand 7 at position 0"
+    );
+}
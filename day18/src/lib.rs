@@ -0,0 +1,197 @@
+use std::error::Error;
+
+use aoc::{
+    grid::Grid,
+    input::{InputSource, parse_input_vec_try},
+    params::{parse_value, unknown},
+    search,
+    solution::{Answer, Solution},
+};
+
+/// Day 18 solution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day18 {
+    /// Maximum coordinate of the memory space
+    pub dim: usize,
+    /// Number of bytes fallen for part 1
+    pub bytes: usize,
+}
+
+impl Day18 {
+    /// Parameters for the puzzle input
+    pub const INPUT: Self = Self {
+        dim: 70,
+        bytes: 1024,
+    };
+
+    /// Parameters for the example
+    pub const EXAMPLE: Self = Self { dim: 6, bytes: 12 };
+}
+
+impl Default for Day18 {
+    fn default() -> Self {
+        Self::INPUT
+    }
+}
+
+impl Solution for Day18 {
+    const DAY: usize = 18;
+
+    type Input = Vec<Coord>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let input = parse_input_vec_try(InputSource::Str(input), input_transform)?;
+
+        // Check the memory space size is representable
+        if (self.dim.checked_add(1))
+            .and_then(|n| n.checked_mul(n))
+            .is_none()
+        {
+            Err(format!("Memory space dimension {} is too large", self.dim))?
+        }
+
+        // Check the bytes fall inside the memory space
+        if let Some(c) = input.iter().find(|c| c.x > self.dim || c.y > self.dim) {
+            Err(format!(
+                "Byte at {},{} is outside the memory space",
+                c.x, c.y
+            ))?
+        }
+
+        // Check enough bytes fall for part 1
+        if self.bytes > input.len() {
+            Err(format!(
+                "Only {} bytes in the input, {} needed",
+                input.len(),
+                self.bytes
+            ))?
+        }
+
+        Ok(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        match part1(self.dim, self.bytes, input) {
+            Some(steps) => steps.into(),
+            None => "No route to the exit".into(),
+        }
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<Answer> {
+        Some(match part2(self.dim, input) {
+            Some(pos) => pos.into(),
+            None => "No solution".into(),
+        })
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("dim", self.dim.to_string()),
+            ("bytes", self.bytes.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Box<dyn Error>> {
+        match name {
+            "dim" => self.dim = parse_value(name, value)?,
+            "bytes" => self.bytes = parse_value(name, value)?,
+            _ => Err(unknown(name))?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the shortest path to the exit after the first bytes have fallen,
+/// or None if the exit can't be reached
+pub fn part1(dim: usize, count: usize, input: &[Coord]) -> Option<u64> {
+    // Create board
+    let board = create_board(input, dim, count);
+
+    // Find shortest path
+    shortest_path(&board).map(|steps| steps as u64)
+}
+
+/// Returns the coordinate of the first byte which blocks the exit
+pub fn part2(dim: usize, input: &[Coord]) -> Option<String> {
+    let blocker = first_blocker(dim, input)?;
+
+    Some(format!("{},{}", input[blocker].x, input[blocker].y))
+}
+
+/// Returns the index of the first byte to fall that blocks the path to the exit,
+/// or None if the exit is still reachable after all of the bytes have fallen
+pub fn first_blocker(dim: usize, input: &[Coord]) -> Option<usize> {
+    let reachable = |count| shortest_path(&create_board(input, dim, count)).is_some();
+
+    if reachable(input.len()) {
+        return None;
+    }
+
+    // Binary chop the list to find the first time a path can't be made to the target.
+    // The exit is reachable after ok bytes and blocked after fail bytes
+    let mut ok = 0;
+    let mut fail = input.len();
+
+    while fail - ok > 1 {
+        let mid = (ok + fail) / 2;
+
+        if reachable(mid) {
+            ok = mid;
+        } else {
+            fail = mid;
+        }
+    }
+
+    Some(fail - 1)
+}
+
+pub type Coord = aoc::geom::Coord<usize>;
+
+/// Creates a board with the first count bytes fallen
+pub fn create_board(input: &[Coord], dim: usize, count: usize) -> Grid<bool> {
+    // Create board
+    let mut board = Grid::new(dim + 1, dim + 1, false);
+
+    // Corrupt memory
+    input.iter().take(count).for_each(|&c| {
+        board[c] = true;
+    });
+
+    board
+}
+
+fn shortest_path(board: &Grid<bool>) -> Option<usize> {
+    search_exit(board).cost()
+}
+
+/// Returns the positions on a shortest path from the start to the exit
+pub fn shortest_route(board: &Grid<bool>) -> Option<Vec<Coord>> {
+    search_exit(board).path()
+}
+
+fn search_exit(board: &Grid<bool>) -> search::SearchResult<Coord, usize> {
+    // Set end point to the bottom right corner
+    let end = Coord::new(board.width() - 1, board.height() - 1);
+
+    // Search from the start point to the end point
+    search::bfs(
+        Coord::default(),
+        |&c| board.neighbours4(c).filter(|&n| !board[n]),
+        |&c| c == end,
+    )
+}
+
+// Input parsing
+
+/// Parses a byte position
+pub fn input_transform(line: &str) -> Result<Coord, Box<dyn Error>> {
+    let (x, y) = line
+        .split_once(',')
+        .ok_or_else(|| format!("Expected x,y but found '{line}'"))?;
+
+    Ok(Coord::new(x.trim().parse()?, y.trim().parse()?))
+}
+
+#[cfg(test)]
+mod tests;
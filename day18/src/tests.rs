@@ -1,5 +1,3 @@
-use aoc::input::parse_test_vec;
-
 use super::*;
 
 const EXAMPLE1: &str = "\
@@ -32,7 +30,60 @@ const EXAMPLE1: &str = "\
 
 #[test]
 fn test1() {
-    let input = parse_test_vec(EXAMPLE1, input_transform).unwrap();
-    assert_eq!(part1(6, 12, &input), 22);
-    assert_eq!(part2(6, &input), "6,1");
+    let input = Day18::EXAMPLE.parse(EXAMPLE1).unwrap();
+    assert_eq!(part1(6, 12, &input), Some(22));
+    assert_eq!(part2(6, &input), Some("6,1".into()));
+}
+
+#[test]
+fn test_params() {
+    let mut day = Day18::default();
+
+    day.set_param("dim", "6").unwrap();
+    day.set_param("bytes", "12").unwrap();
+    assert_eq!(day, Day18::EXAMPLE);
+
+    assert!(day.set_param("size", "6").is_err());
+    assert!(day.set_param("dim", "-1").is_err());
+
+    let input = day.parse(EXAMPLE1).unwrap();
+    assert_eq!(day.part1(&input), Answer::Int(22));
+
+    // Bytes must fall inside the memory space
+    assert!(Day18 { dim: 5, bytes: 12 }.parse(EXAMPLE1).is_err());
+}
+
+#[test]
+fn test_errors() {
+    // Too few bytes for part 1
+    assert!(Day18 { dim: 6, bytes: 26 }.parse(EXAMPLE1).is_err());
+
+    // Dimension too large
+    assert!(
+        Day18 {
+            dim: usize::MAX,
+            bytes: 0
+        }
+        .parse(EXAMPLE1)
+        .is_err()
+    );
+
+    // Bad byte positions
+    assert!(Day18::EXAMPLE.parse("1,2\n3\n").is_err());
+    assert!(Day18::EXAMPLE.parse("1,x\n").is_err());
+
+    // No bytes
+    let day = Day18 { dim: 6, bytes: 0 };
+    let input = day.parse("").unwrap();
+    assert_eq!(day.part1(&input), Answer::Int(12));
+    assert_eq!(day.part2(&input), Some(Answer::Str("No solution".into())));
+
+    // Exit blocked for part 1
+    let day = Day18 { dim: 1, bytes: 2 };
+    let input = day.parse("1,0\n0,1\n").unwrap();
+    assert_eq!(
+        day.part1(&input),
+        Answer::Str("No route to the exit".into())
+    );
+    assert_eq!(day.part2(&input), Some(Answer::Str("0,1".into())));
 }
@@ -0,0 +1,218 @@
+use std::error::Error;
+
+use aoc::{
+    geom,
+    grid::Grid,
+    params::{parse_value, unknown},
+    search,
+    solution::{Answer, Solution},
+};
+use fxhash::FxHashMap;
+
+/// Day 20 solution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day20 {
+    /// Longest cheat in picoseconds for part 1
+    pub cheat1: usize,
+    /// Longest cheat in picoseconds for part 2
+    pub cheat2: usize,
+    /// Fewest picoseconds a cheat must save to be counted
+    pub min_saving: usize,
+}
+
+impl Day20 {
+    /// Parameters for the puzzle input
+    pub const INPUT: Self = Self {
+        cheat1: 2,
+        cheat2: 20,
+        min_saving: 100,
+    };
+
+    /// Parameters for the example
+    pub const EXAMPLE: Self = Self {
+        cheat1: 2,
+        cheat2: 20,
+        min_saving: 50,
+    };
+}
+
+impl Default for Day20 {
+    fn default() -> Self {
+        Self::INPUT
+    }
+}
+
+impl Solution for Day20 {
+    const DAY: usize = 20;
+
+    type Input = (Grid<Tile>, FxHashMap<Coord, usize>);
+
+    const SHARED_PARSE: bool = true;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let map = Grid::parse_try(input, input_transform)?;
+
+        // Get map of coord to path index
+        let pathmap = find_path(&map);
+
+        Ok((map, pathmap))
+    }
+
+    fn part1(&self, (map, pathmap): &Self::Input) -> Answer {
+        count_cheats(map, pathmap, self.cheat1, self.min_saving).into()
+    }
+
+    fn part2(&self, (map, pathmap): &Self::Input) -> Option<Answer> {
+        Some(count_cheats(map, pathmap, self.cheat2, self.min_saving).into())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("cheat1", self.cheat1.to_string()),
+            ("cheat2", self.cheat2.to_string()),
+            ("min_saving", self.min_saving.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Box<dyn Error>> {
+        match name {
+            "cheat1" => self.cheat1 = parse_value(name, value)?,
+            "cheat2" => self.cheat2 = parse_value(name, value)?,
+            "min_saving" => self.min_saving = parse_value(name, value)?,
+            _ => Err(unknown(name))?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Counts the cheats of up to duration picoseconds saving at least cutoff picoseconds
+pub fn count_cheats(
+    map: &Grid<Tile>,
+    pathmap: &FxHashMap<Coord, usize>,
+    duration: usize,
+    cutoff: usize,
+) -> u64 {
+    find_cheats(map, pathmap, duration, cutoff).count() as u64
+}
+
+/// Walks the path from start to end, returning the index of each position on it
+pub fn find_path(map: &Grid<Tile>) -> FxHashMap<Coord, usize> {
+    // Find start and end positions
+    let start = map.find_tile(&Tile::Start).unwrap();
+    let end = map.find_tile(&Tile::End).unwrap();
+
+    // Walk the corridor, building coord -> path index map
+    search::bfs(start, |&pos| next_pos(map, pos), |&pos| pos == end).into_costs()
+}
+
+fn next_pos(map: &Grid<Tile>, pos: Coord) -> impl Iterator<Item = Coord> {
+    // Find adjacent positions that are not a wall
+    map.neighbours4(pos).filter(|&npos| map[npos] != Tile::Wall)
+}
+
+/// Cheat jumping from one position on the path to a later one
+pub struct Cheat {
+    pub from: Coord,
+    pub from_idx: usize,
+    pub to: Coord,
+    pub to_idx: usize,
+    pub saved: usize,
+}
+
+/// Returns the cheats of up to duration picoseconds that save at least cutoff picoseconds
+pub fn find_cheats<'a>(
+    map: &'a Grid<Tile>,
+    pathmap: &'a FxHashMap<Coord, usize>,
+    duration: usize,
+    cutoff: usize,
+) -> impl Iterator<Item = Cheat> + 'a {
+    // Iterate the path map
+    pathmap.iter().flat_map(move |(&pos, &idx)| {
+        // Iterate the duration range
+        (2..=duration).flat_map(move |duration| {
+            // Iterate the valid jump positions for the duration
+            cheat_jumps(map, pos, duration).filter_map(move |cheat_pos| {
+                // Is this jumped to position on the path?
+                if let Some(cheat_idx) = pathmap.get(&cheat_pos) {
+                    // Yes - check the position on the path is later than the current position
+                    if *cheat_idx > idx && *cheat_idx > duration {
+                        // It is - calculate the saved picoseconds
+                        let saved = cheat_idx - idx - duration;
+
+                        // Check against cutoff
+                        if saved >= cutoff {
+                            return Some(Cheat {
+                                from: pos,
+                                from_idx: idx,
+                                to: cheat_pos,
+                                to_idx: *cheat_idx,
+                                saved,
+                            });
+                        }
+                    }
+                }
+
+                // Jumped to position is not valid
+                None
+            })
+        })
+    })
+}
+
+fn cheat_jumps(map: &Grid<Tile>, pos: Coord, duration: usize) -> impl Iterator<Item = Coord> {
+    // Generate the valid jump positions for the duration
+    //
+    // eg duration = 3:
+    //
+    //    1
+    //   4.1    1 = ne
+    //  4...1   2 = se
+    // 4..P..2  3 = sw
+    //  3...2   4 = nw
+    //   3.2    P = position
+    //    3
+
+    // Offsets for each direction given movement a and b
+    let ne = |a: isize, b: isize| geom::Coord::new(a, -b);
+    let se = |a: isize, b: isize| geom::Coord::new(b, a);
+    let sw = |a: isize, b: isize| geom::Coord::new(-a, b);
+    let nw = |a: isize, b: isize| geom::Coord::new(-b, -a);
+
+    // Iterate the duration range and generate the jump position for each direction
+    (0..duration)
+        .map(move |i| (i as isize, (duration - i) as isize))
+        .flat_map(move |(a, b)| [ne(a, b), se(a, b), sw(a, b), nw(a, b)])
+        .filter_map(move |offset| {
+            // Check bounds and not a wall
+            map.offset(pos, offset)
+                .filter(|&jump_pos| map[jump_pos] != Tile::Wall)
+        })
+}
+
+pub type Coord = aoc::geom::Coord<usize>;
+
+/// Map tile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Start,
+    End,
+}
+
+// Input parsing
+
+/// Parses a map tile
+pub fn input_transform(c: char) -> Result<Tile, String> {
+    match c {
+        '.' => Ok(Tile::Empty),
+        '#' => Ok(Tile::Wall),
+        'S' => Ok(Tile::Start),
+        'E' => Ok(Tile::End),
+        _ => Err(format!("Invalid tile '{c}'")),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -1,7 +1,5 @@
 use std::collections::BTreeMap;
 
-use aoc::input::parse_test_vec;
-
 use super::*;
 
 const EXAMPLE: &str = "\
@@ -24,7 +22,7 @@ const EXAMPLE: &str = "\
 
 #[test]
 fn test1() {
-    let input = parse_test_vec(EXAMPLE, input_transform).unwrap();
+    let input = Grid::parse_try(EXAMPLE, input_transform).unwrap();
 
     let path = find_path(&input);
 
@@ -33,7 +31,7 @@ fn test1() {
 
 #[test]
 fn test2() {
-    let input = parse_test_vec(EXAMPLE, input_transform).unwrap();
+    let input = Grid::parse_try(EXAMPLE, input_transform).unwrap();
     let pathmap = find_path(&input);
     let mut cheat_map = cheat_map(find_cheats(&input, &pathmap, 2, 2)).into_iter();
 
@@ -65,7 +63,7 @@ fn test2() {
 
 #[test]
 fn test3() {
-    let input = parse_test_vec(EXAMPLE, input_transform).unwrap();
+    let input = Grid::parse_try(EXAMPLE, input_transform).unwrap();
     let pathmap = find_path(&input);
     let mut cheat_map = cheat_map(find_cheats(&input, &pathmap, 20, 50)).into_iter();
 
@@ -101,51 +99,64 @@ fn test3() {
     assert_eq!(cheat_map.next(), None);
 }
 
+#[test]
+fn test_params() {
+    let mut day = Day20::default();
+
+    day.set_param("min_saving", "50").unwrap();
+    assert_eq!(day, Day20::EXAMPLE);
+    assert!(day.set_param("cheat", "3").is_err());
+
+    let input = day.parse(EXAMPLE).unwrap();
+    assert_eq!(day.part1(&input), Answer::Int(1));
+    assert_eq!(day.part2(&input), Some(Answer::Int(285)));
+}
+
 #[test]
 fn test4() {
-    let map = vec![vec![Tile::Empty; 7]; 7];
-
-    let mut jumps = cheat_jumps(&map, (3, 3), 3);
-
-    assert_eq!(jumps.next(), Some((3, 0)));
-    assert_eq!(jumps.next(), Some((6, 3)));
-    assert_eq!(jumps.next(), Some((3, 6)));
-    assert_eq!(jumps.next(), Some((0, 3)));
-    assert_eq!(jumps.next(), Some((4, 1)));
-    assert_eq!(jumps.next(), Some((5, 4)));
-    assert_eq!(jumps.next(), Some((2, 5)));
-    assert_eq!(jumps.next(), Some((1, 2)));
-    assert_eq!(jumps.next(), Some((5, 2)));
-    assert_eq!(jumps.next(), Some((4, 5)));
-    assert_eq!(jumps.next(), Some((1, 4)));
-    assert_eq!(jumps.next(), Some((2, 1)));
+    let map = Grid::new(7, 7, Tile::Empty);
+
+    let mut jumps = cheat_jumps(&map, Coord::new(3, 3), 3);
+
+    assert_eq!(jumps.next(), Some(Coord::new(3, 0)));
+    assert_eq!(jumps.next(), Some(Coord::new(6, 3)));
+    assert_eq!(jumps.next(), Some(Coord::new(3, 6)));
+    assert_eq!(jumps.next(), Some(Coord::new(0, 3)));
+    assert_eq!(jumps.next(), Some(Coord::new(4, 1)));
+    assert_eq!(jumps.next(), Some(Coord::new(5, 4)));
+    assert_eq!(jumps.next(), Some(Coord::new(2, 5)));
+    assert_eq!(jumps.next(), Some(Coord::new(1, 2)));
+    assert_eq!(jumps.next(), Some(Coord::new(5, 2)));
+    assert_eq!(jumps.next(), Some(Coord::new(4, 5)));
+    assert_eq!(jumps.next(), Some(Coord::new(1, 4)));
+    assert_eq!(jumps.next(), Some(Coord::new(2, 1)));
 
     assert_eq!(jumps.next(), None);
 }
 
 #[test]
 fn test5() {
-    let map = vec![vec![Tile::Empty; 5]; 5];
+    let map = Grid::new(5, 5, Tile::Empty);
 
-    let mut jumps = cheat_jumps(&map, (2, 2), 3);
+    let mut jumps = cheat_jumps(&map, Coord::new(2, 2), 3);
 
-    assert_eq!(jumps.next(), Some((3, 0)));
-    assert_eq!(jumps.next(), Some((4, 3)));
-    assert_eq!(jumps.next(), Some((1, 4)));
-    assert_eq!(jumps.next(), Some((0, 1)));
-    assert_eq!(jumps.next(), Some((4, 1)));
-    assert_eq!(jumps.next(), Some((3, 4)));
-    assert_eq!(jumps.next(), Some((0, 3)));
-    assert_eq!(jumps.next(), Some((1, 0)));
+    assert_eq!(jumps.next(), Some(Coord::new(3, 0)));
+    assert_eq!(jumps.next(), Some(Coord::new(4, 3)));
+    assert_eq!(jumps.next(), Some(Coord::new(1, 4)));
+    assert_eq!(jumps.next(), Some(Coord::new(0, 1)));
+    assert_eq!(jumps.next(), Some(Coord::new(4, 1)));
+    assert_eq!(jumps.next(), Some(Coord::new(3, 4)));
+    assert_eq!(jumps.next(), Some(Coord::new(0, 3)));
+    assert_eq!(jumps.next(), Some(Coord::new(1, 0)));
 
     assert_eq!(jumps.next(), None);
 }
 
-fn cheat_map(cheats: impl Iterator<Item = usize>) -> BTreeMap<usize, u8> {
+fn cheat_map(cheats: impl Iterator<Item = Cheat>) -> BTreeMap<usize, u8> {
     let mut cheat_map = BTreeMap::new();
 
-    cheats.for_each(|saved| {
-        *cheat_map.entry(saved).or_insert(0) += 1u8;
+    cheats.for_each(|cheat| {
+        *cheat_map.entry(cheat.saved).or_insert(0) += 1u8;
     });
 
     cheat_map
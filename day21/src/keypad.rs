@@ -1,5 +1,4 @@
-use std::{cmp::Ordering, collections::VecDeque};
-
+use aoc::search;
 use fxhash::FxHashMap;
 
 type Coord = (usize, usize);
@@ -96,98 +95,42 @@ impl KeyPadBuilder {
     }
 
     fn build_key_routes(&self, from: &Coord, to: &Coord) -> Vec<Vec<Key>> {
-        // Initialise work queue
-        let mut queue = VecDeque::new();
-
-        queue.push_back(BuildWork {
-            coord: *from,
-            dir: None,
-            steps: 0,
-            dir_changes: 0,
-            path: vec![],
-        });
-
-        // Create visited hashmap
-        let mut visited = FxHashMap::default();
-
-        // Best paths
-        let mut best_len = usize::MAX;
-        let mut best_paths = Vec::new();
-
-        // Process work queue
-        while let Some(mut work) = queue.pop_front() {
-            // Reached end point?
-            if work.coord == *to {
-                // Yes
-                work.path.push(Key::Action(Action::Activate));
-
-                match work.path.len().cmp(&best_len) {
-                    std::cmp::Ordering::Less => {
-                        // Fewer steps - update best
-                        best_len = work.path.len();
-                        best_paths = vec![work.path];
-                    }
-                    std::cmp::Ordering::Equal => {
-                        // Same steps - add path
-                        best_paths.push(work.path);
-                    }
-                    std::cmp::Ordering::Greater => (),
-                }
-
-                continue;
-            }
-
-            // Already visited?
-            if let Some(steps) = visited.get_mut(&work.coord) {
-                // Yes - visited in fewer steps?
-                match work.path.len().cmp(steps) {
-                    Ordering::Less => {
-                        // Update fewest steps
-                        *steps = work.path.len();
-                    }
-                    Ordering::Equal => (),
-                    Ordering::Greater => continue,
-                }
-            } else {
-                // No - mark as visited
-                visited.insert(work.coord, work.path.len());
-            }
-
-            // Loop next positions
-            for (next, action) in self.pos_from(work.coord) {
-                // Direction changed?
-                let dir = Some(action);
-                let mut dir_changes = work.dir_changes;
-
-                if dir != work.dir {
-                    dir_changes += 1;
-
-                    // Only allow up to 2 direction changes
-                    if dir_changes > 2 {
-                        continue;
+        // Search state is the coordinate, last direction moved and number of direction changes
+        let result = search::bfs_all(
+            (*from, None, 0u8),
+            |&(coord, dir, dir_changes)| {
+                self.pos_from(coord).filter_map(move |(next, action)| {
+                    // Direction changed?
+                    let next_dir = Some(action);
+                    let mut dir_changes = dir_changes;
+
+                    if next_dir != dir {
+                        dir_changes += 1;
+
+                        // Only allow up to 2 direction changes
+                        if dir_changes > 2 {
+                            return None;
+                        }
                     }
-                }
-
-                // Add a step
-                let steps = work.steps + 1;
-
-                // Build new path
-                let mut path = work.path.clone();
-                path.push(Key::Action(action));
-
-                // Add to work queue
-                queue.push_back(BuildWork {
-                    coord: next,
-                    dir,
-                    steps,
-                    dir_changes,
-                    path,
-                });
-            }
-        }
 
-        // Return next paths
-        best_paths
+                    Some((next, next_dir, dir_changes))
+                })
+            },
+            |&(coord, _, _)| coord == *to,
+        );
+
+        // Convert each best path to key presses followed by activate
+        result
+            .all_paths()
+            .into_iter()
+            .map(|path| {
+                path.into_iter()
+                    .filter_map(|(_, dir, _)| dir)
+                    .map(Key::Action)
+                    .chain([Key::Action(Action::Activate)])
+                    .collect()
+            })
+            .collect()
     }
 
     const DIRS: [([isize; 2], Action); 4] = [
@@ -201,11 +144,9 @@ impl KeyPadBuilder {
         Self::DIRS.iter().filter_map(move |([dx, dy], action)| {
             match x.checked_add_signed(*dx) {
                 Some(nx) if nx < self.width => match y.checked_add_signed(*dy) {
-                    Some(ny) if ny < self.height => {
-                        // Check this coordinate contains a key
-                        if self.keys.contains_key(&(nx, ny)) {
-                            return Some(((nx, ny), *action));
-                        }
+                    // Check this coordinate contains a key
+                    Some(ny) if ny < self.height && self.keys.contains_key(&(nx, ny)) => {
+                        return Some(((nx, ny), *action));
                     }
                     _ => (),
                 },
@@ -216,12 +157,3 @@ impl KeyPadBuilder {
         })
     }
 }
-
-#[derive(PartialEq, Eq)]
-struct BuildWork {
-    coord: Coord,
-    dir: Option<Action>,
-    steps: u8,
-    dir_changes: u8,
-    path: Vec<Key>,
-}
@@ -10,24 +10,30 @@ fn test1() {
     use keypad::Action::*;
     use keypad::Key::*;
 
-    assert_eq!(keypads[0].routes(Action(Activate), Num(0)), &vec![vec![
-        Action(Left),
-        Action(Activate)
-    ]]);
-    assert_eq!(keypads[0].routes(Num(0), Num(2)), &vec![vec![
-        Action(Up),
-        Action(Activate)
-    ]]);
-    assert_eq!(keypads[0].routes(Num(2), Num(9)), &vec![
-        vec![Action(Up), Action(Up), Action(Right), Action(Activate)],
-        vec![Action(Right), Action(Up), Action(Up), Action(Activate)],
-    ]);
-    assert_eq!(keypads[0].routes(Num(9), Action(Activate)), &vec![vec![
-        Action(Down),
-        Action(Down),
-        Action(Down),
-        Action(Activate)
-    ]]);
+    assert_eq!(
+        keypads[0].routes(Action(Activate), Num(0)),
+        &vec![vec![Action(Left), Action(Activate)]]
+    );
+    assert_eq!(
+        keypads[0].routes(Num(0), Num(2)),
+        &vec![vec![Action(Up), Action(Activate)]]
+    );
+    assert_eq!(
+        keypads[0].routes(Num(2), Num(9)),
+        &vec![
+            vec![Action(Up), Action(Up), Action(Right), Action(Activate)],
+            vec![Action(Right), Action(Up), Action(Up), Action(Activate)],
+        ]
+    );
+    assert_eq!(
+        keypads[0].routes(Num(9), Action(Activate)),
+        &vec![vec![
+            Action(Down),
+            Action(Down),
+            Action(Down),
+            Action(Activate)
+        ]]
+    );
 }
 
 #[test]
@@ -83,5 +89,5 @@ fn test5() {
 
     let (numkeypad, dirkeypad) = build_keypads();
 
-    assert_eq!(part1(&input, &numkeypad, &dirkeypad), 126384);
+    assert_eq!(solve_chain(&input, 2, &numkeypad, &dirkeypad), 126384);
 }
@@ -0,0 +1,159 @@
+#![feature(portable_simd)]
+
+use std::error::Error;
+use std::num::ParseIntError;
+use std::simd::prelude::*;
+
+use aoc::{
+    input::{InputSource, parse_input_vec_try},
+    params::{parse_value, unknown},
+    solution::{Answer, Solution},
+};
+
+/// Day 22 solution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day22 {
+    /// Number of secret numbers each buyer generates
+    pub iters: usize,
+}
+
+impl Day22 {
+    /// Parameters for the puzzle input
+    pub const INPUT: Self = Self { iters: 2000 };
+}
+
+impl Default for Day22 {
+    fn default() -> Self {
+        Self::INPUT
+    }
+}
+
+impl Solution for Day22 {
+    const DAY: usize = 22;
+
+    type Input = Vec<u64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_input_vec_try(
+            InputSource::Str(input),
+            input_transform,
+        )?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(self.iters, input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<Answer> {
+        Some(part2(self.iters, input).into())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("iters", self.iters.to_string())]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Box<dyn Error>> {
+        match name {
+            "iters" => self.iters = parse_value(name, value)?,
+            _ => Err(unknown(name))?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Sums the last secret number of each buyer after a number of iterations
+pub fn part1(iters: usize, input: &[u64]) -> u64 {
+    input
+        .iter()
+        .map(|line| {
+            let mut secret = *line;
+
+            // Do hash iterations
+            for _ in 0..iters {
+                hashstep(&mut secret);
+            }
+
+            secret
+        })
+        .sum()
+}
+
+const RANGE: usize = 19;
+const RANGEP2: usize = RANGE.pow(2);
+const RANGEP3: usize = RANGE.pow(3);
+const RANGEP4: usize = RANGE.pow(4);
+
+const MULT: Simd<u16, 4> = u16x4::from_array([1, RANGE as u16, RANGEP2 as u16, RANGEP3 as u16]);
+
+/// Returns the most bananas available from a single sequence of four price changes
+pub fn part2(iters: usize, input: &[u64]) -> u64 {
+    // Map 4 price changes to total number of bananas
+    let mut set = [false; RANGEP4];
+    let mut bananas = [0u16; RANGEP4];
+
+    for line in input {
+        // Calculate the prices
+        let mut secret = *line;
+
+        let prices = (0..iters)
+            .map(|_| {
+                hashstep(&mut secret);
+                (secret % 10) as u8
+            })
+            .collect::<Vec<_>>();
+
+        // Initialise set flags
+        set.fill(false);
+
+        // Calculate the price changes
+        let diffs = prices
+            .windows(2)
+            .map(|a| ((a[1] as i8 - a[0] as i8) + 9) as u16) // range 0-18
+            .collect::<Vec<_>>();
+
+        // Map windows of 4 price changes
+        diffs
+            .windows(4)
+            .map(|arr| {
+                // ... build array element
+                let diffs = u16x4::from_slice(arr);
+                let diffs_mult = diffs * MULT;
+                diffs_mult.reduce_sum() as usize
+            })
+            .enumerate()
+            .for_each(|(i, elem)| {
+                // Already set?
+                if !set[elem] {
+                    // No - accumulate
+                    bananas[elem] += prices[i + 4] as u16;
+                    set[elem] = true;
+                }
+            });
+    }
+
+    // Get the max number of bananas possible
+    let max = bananas.iter().max().unwrap();
+
+    *max as u64
+}
+
+fn hashstep(secret: &mut u64) {
+    *secret ^= *secret << 6;
+    *secret &= 0xffffff;
+
+    *secret ^= *secret >> 5;
+
+    *secret ^= *secret << 11;
+    *secret &= 0xffffff;
+}
+
+// Input parsing
+
+/// Parses a buyer's initial secret number
+pub fn input_transform(line: &str) -> Result<u64, ParseIntError> {
+    line.parse::<u64>()
+}
+
+#[cfg(test)]
+mod tests;
@@ -82,5 +82,5 @@ const EXAMPLE3: &str = "\
 #[test]
 fn test3() {
     let input = parse_test_vec(EXAMPLE3, |line| line.parse::<u64>().unwrap()).unwrap();
-    assert_eq!(part2(&input), 23);
+    assert_eq!(part2(2000, &input), 23);
 }
@@ -0,0 +1,83 @@
+use std::error::Error;
+
+use aoc::solution::{Answer, Solution};
+
+mod graph;
+pub use graph::Graph;
+
+/// Day 23 solution
+#[derive(Clone)]
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: usize = 23;
+
+    type Input = Graph;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_input_str(input))
+    }
+
+    fn part1(&self, graph: &Self::Input) -> Answer {
+        part1(graph).into()
+    }
+
+    fn part2(&self, graph: &Self::Input) -> Option<Answer> {
+        Some(part2(graph).into())
+    }
+}
+
+/// Counts the sets of three connected computers with a name starting with t
+pub fn part1(graph: &Graph) -> u64 {
+    let mut count = 0;
+
+    // Walk the graph finding sets of interconnected nodes
+    graph.walk(&mut |set| {
+        // Got a set of three?
+        if set.len() == 3 {
+            // Yes - check if any start with 't'
+            if set.iter().any(|&n| graph.node_name(n).starts_with('t')) {
+                // Yes - count
+                count += 1;
+            }
+
+            false
+        } else {
+            true
+        }
+    });
+
+    // Return count
+    count
+}
+
+/// Returns the password from the largest set of connected computers
+pub fn part2(graph: &Graph) -> String {
+    // Get maximum cliques for the graph
+    let max_cliques = graph.max_cliques();
+
+    // Should only be one
+    assert_eq!(max_cliques.len(), 1);
+
+    // Return separated by ,
+    max_cliques[0].join(",")
+}
+
+// Input parsing
+
+/// Parses the network connections into a graph
+pub fn parse_input_str(input: &str) -> Graph {
+    // Create new graph
+    let mut graph = Graph::default();
+
+    // Process each line of the input
+    for line in input.lines() {
+        let mut nodes = line.split('-');
+        graph.add_edge(nodes.next().unwrap(), nodes.next().unwrap());
+    }
+
+    graph
+}
+
+#[cfg(test)]
+mod tests;
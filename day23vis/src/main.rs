@@ -1,6 +1,7 @@
 use std::{collections::HashMap, error::Error};
 
 use aoc::input::parse_input_vec;
+use aoc::vis::Options;
 use graph::Graph;
 use hsl::HSL;
 
@@ -9,6 +10,9 @@ mod graph;
 // Convert with: neato -x -Goverlap=false -Tsvg day23.dot -o day23.svg
 
 fn main() -> Result<(), Box<dyn Error>> {
+    // Get options. Only the output path applies to a graph
+    let options = Options::from_args()?.output_only()?;
+
     // Get input
     let input = parse_input_vec(23, input_transform)?;
     let graph = build_graph(input);
@@ -80,7 +84,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
     }
 
-    graph.dump("vis/day23.dot", &highlights)?;
+    graph.dump(&options.output("vis/day23.dot"), &highlights)?;
 
     Ok(())
 }
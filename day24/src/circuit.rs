@@ -23,7 +23,7 @@ impl std::fmt::Display for Op {
 
 // Gate
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Gate {
     op: Op,
 }
@@ -75,7 +75,7 @@ impl Edge {
 
 // Input
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Input {
     name: String,
     state: bool,
@@ -115,7 +115,7 @@ impl Output {
 
 // Circuit
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Circuit {
     inputs: Vec<Input>,
     gates: Vec<Gate>,
@@ -1,6 +1,7 @@
 use std::error::Error;
 
 use aoc::input::parse_input;
+use aoc::vis::Options;
 use fxhash::{FxHashMap, FxHashSet};
 
 mod circuit;
@@ -9,6 +10,9 @@ use circuit::{Circuit, Conn, Edge, Gate, Input, Op, Output};
 // Convert with: dot -Tsvg -o day24.svg day24.dot
 
 fn main() -> Result<(), Box<dyn Error>> {
+    // Get options. Only the output path applies to a graph
+    let options = Options::from_args()?.output_only()?;
+
     // Get input
     let mut circuit = parse_input(24, parse_input_str)?;
 
@@ -57,7 +61,12 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
     }
 
-    circuit.dump("vis/day24.dot", &layout, &carries, &error_list)?;
+    circuit.dump(
+        &options.output("vis/day24.dot"),
+        &layout,
+        &carries,
+        &error_list,
+    )?;
 
     Ok(())
 }
@@ -419,8 +428,8 @@ fn parse_input_str(input: &str) -> Circuit {
 
     // Build outputs
     let mut outputs = gate_out
-        .iter()
-        .filter_map(|(wire, _)| {
+        .keys()
+        .filter_map(|wire| {
             if !gate_in.contains_key(wire) {
                 // Must be an output
                 Some(Output::new(wire))
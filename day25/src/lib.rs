@@ -0,0 +1,104 @@
+use std::error::Error;
+
+use aoc::{
+    input::sections,
+    solution::{Answer, Solution},
+};
+
+/// Day 25 solution
+#[derive(Clone)]
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: usize = 25;
+
+    type Input = (Vec<Lock>, Vec<Key>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_input_str(input))
+    }
+
+    fn part1(&self, (locks, keys): &Self::Input) -> Answer {
+        part1(locks, keys).into()
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Option<Answer> {
+        None
+    }
+}
+
+/// Counts the lock and key pairs which fit together
+pub fn part1(locks: &[Lock], keys: &[Key]) -> u64 {
+    // Iterate each lock
+    locks
+        .iter()
+        .map(|lock| {
+            // Iterate each key
+            keys.iter()
+                .filter(|key| {
+                    // Does this key fit in this lock?
+                    lock.heights
+                        .iter()
+                        .zip(key.heights.iter())
+                        .map(|(l, k)| l + k)
+                        .all(|s| s <= 5)
+                })
+                .count() as u64
+        })
+        .sum()
+}
+
+// Input parsing
+
+#[derive(Debug)]
+pub struct Key {
+    heights: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub struct Lock {
+    heights: Vec<u8>,
+}
+
+/// Parses the lock and key schematics
+pub fn parse_input_str(input: &str) -> (Vec<Lock>, Vec<Key>) {
+    let blocks = sections(input);
+
+    let mut locks = Vec::new();
+    let mut keys = Vec::new();
+
+    for block in blocks {
+        let line1 = block.lines().next().unwrap();
+        let len = line1.len();
+        let lock = line1.starts_with('#');
+
+        let iter: Box<dyn Iterator<Item = &str>> = if lock {
+            Box::new(block.lines())
+        } else {
+            Box::new(block.as_str().lines().rev())
+        };
+
+        let heights = iter
+            .enumerate()
+            .fold(vec![0; len], |mut heights, (y, line)| {
+                line.chars().enumerate().for_each(|(x, c)| {
+                    if c == '#' {
+                        heights[x] = y as u8;
+                    }
+                });
+
+                heights
+            });
+
+        if lock {
+            locks.push(Lock { heights })
+        } else {
+            keys.push(Key { heights })
+        }
+    }
+
+    (locks, keys)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,283 @@
+use std::{
+    error::Error,
+    fs,
+    ops::RangeInclusive,
+    time::{Duration, Instant},
+};
+
+use aoc::{input::parse_input, solution::DaySolution};
+use serde::{Deserialize, Serialize};
+
+use crate::days::DAYS;
+
+/// Benchmark options
+pub struct BenchOptions {
+    /// Number of timed runs per day
+    pub runs: usize,
+    /// JSON output file
+    pub json: Option<String>,
+    /// CSV output file
+    pub csv: Option<String>,
+}
+
+impl BenchOptions {
+    /// Parses benchmark options from command line arguments
+    pub fn parse(args: &[&str]) -> Result<Self, Box<dyn Error>> {
+        let mut options = Self {
+            runs: 10,
+            json: None,
+            csv: None,
+        };
+
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("No value given for {arg}"));
+
+            match *arg {
+                "--runs" => {
+                    options.runs = match value()?.parse::<usize>() {
+                        Ok(runs) if runs > 0 => runs,
+                        _ => Err("Number of runs must be a positive integer")?,
+                    }
+                }
+                "--json" => options.json = Some(value()?.to_string()),
+                "--csv" => options.csv = Some(value()?.to_string()),
+                _ => Err(format!("Unknown option '{arg}'"))?,
+            }
+        }
+
+        // Default to JSON results in the stats directory
+        if options.json.is_none() && options.csv.is_none() {
+            options.json = Some(format!("stats/bench-{}.json", std::env::consts::OS));
+        }
+
+        Ok(options)
+    }
+}
+
+/// Benchmark results for a set of days
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct BenchResults {
+    /// Operating system the benchmark ran on
+    pub os: String,
+    /// Timing for each step of each day
+    pub timings: Vec<Timing>,
+}
+
+impl BenchResults {
+    /// Loads benchmark results from a JSON or CSV file
+    pub fn load(file: &str) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(file).map_err(|e| format!("{file}: {e}"))?;
+
+        if file.ends_with(".csv") {
+            from_csv(&text).map_err(|e| format!("{file}: {e}").into())
+        } else {
+            serde_json::from_str(&text).map_err(|e| format!("{file}: {e}").into())
+        }
+    }
+}
+
+/// Timing statistics for one step (parse, part1 or part2) of a day. The parse step is
+/// named parse* when it includes solving work shared by both parts
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: usize,
+    pub step: String,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl Timing {
+    fn new(day: usize, step: &str, mut times: Vec<Duration>) -> Self {
+        times.sort();
+
+        let nanos = |d: Duration| d.as_nanos() as u64;
+
+        Self {
+            day,
+            step: step.to_string(),
+            runs: times.len(),
+            min_ns: nanos(times[0]),
+            median_ns: nanos(times[times.len() / 2]),
+            mean_ns: nanos(times.iter().sum::<Duration>() / times.len() as u32),
+        }
+    }
+}
+
+/// Benchmarks a range of days and writes the results
+pub fn bench(days: RangeInclusive<usize>, options: BenchOptions) -> Result<(), Box<dyn Error>> {
+    let mut timings = Vec::new();
+    let mut failed = 0;
+    let mut shared = false;
+
+    println!(
+        "{:>3} {:<6} {:>12} {:>12} {:>12}",
+        "Day", "Step", "Min", "Median", "Mean"
+    );
+
+    for day in days {
+        let result = parse_input(day, |input| bench_day(DAYS[day - 1], input, options.runs));
+
+        match result.map_err(|e| e.into()).and_then(|r| r) {
+            Ok(day_timings) => {
+                for t in &day_timings {
+                    println!(
+                        "{:>3} {:<6} {:>12} {:>12} {:>12}",
+                        t.day,
+                        t.step,
+                        format_ns(t.min_ns),
+                        format_ns(t.median_ns),
+                        format_ns(t.mean_ns)
+                    );
+                }
+
+                shared |= DAYS[day - 1].shared_parse();
+
+                timings.extend(day_timings);
+            }
+            Err(e) => {
+                println!("{day:>3} Error: {e}");
+                failed += 1;
+            }
+        }
+    }
+
+    if shared {
+        println!("parse* includes solving work shared by both parts");
+    }
+
+    let results = BenchResults {
+        os: std::env::consts::OS.to_string(),
+        timings,
+    };
+
+    // Write results
+    if let Some(file) = &options.json {
+        fs::write(file, serde_json::to_string_pretty(&results)? + "\n")?;
+    }
+
+    if let Some(file) = &options.csv {
+        fs::write(file, to_csv(&results))?;
+    }
+
+    if failed > 0 {
+        Err(format!("{failed} day(s) failed"))?
+    }
+
+    Ok(())
+}
+
+/// Times parse, part 1 and part 2 of a day over a number of runs
+fn bench_day(
+    solution: &dyn DaySolution,
+    input: &str,
+    runs: usize,
+) -> Result<Vec<Timing>, Box<dyn Error>> {
+    let mut parse_times = Vec::with_capacity(runs);
+    let mut part_times = [Vec::with_capacity(runs), Vec::with_capacity(runs)];
+
+    // Untimed warm up run followed by the timed runs
+    for run in 0..=runs {
+        let start = Instant::now();
+        let parsed = solution.parse_any(input)?;
+        let parse_time = start.elapsed();
+
+        let mut times = [None, None];
+
+        for (part, time) in times.iter_mut().enumerate() {
+            let start = Instant::now();
+
+            if solution.solve_part(part + 1, parsed.as_ref()).is_some() {
+                *time = Some(start.elapsed());
+            }
+        }
+
+        if run > 0 {
+            parse_times.push(parse_time);
+
+            for (part_time, time) in part_times.iter_mut().zip(times) {
+                part_time.extend(time);
+            }
+        }
+    }
+
+    let day = solution.day();
+
+    // Label parse timings which include part of the solve
+    let parse_step = if solution.shared_parse() {
+        "parse*"
+    } else {
+        "parse"
+    };
+
+    let mut timings = vec![Timing::new(day, parse_step, parse_times)];
+
+    for (part, times) in part_times.into_iter().enumerate() {
+        if !times.is_empty() {
+            timings.push(Timing::new(day, &format!("part{}", part + 1), times));
+        }
+    }
+
+    Ok(timings)
+}
+
+/// Formats benchmark results as CSV
+fn to_csv(results: &BenchResults) -> String {
+    let mut csv = String::from("os,day,step,runs,min_ns,median_ns,mean_ns\n");
+
+    for t in &results.timings {
+        csv += &format!(
+            "{},{},{},{},{},{},{}\n",
+            results.os, t.day, t.step, t.runs, t.min_ns, t.median_ns, t.mean_ns
+        );
+    }
+
+    csv
+}
+
+/// Parses benchmark results from CSV
+fn from_csv(text: &str) -> Result<BenchResults, Box<dyn Error>> {
+    let mut os = String::new();
+    let mut timings = Vec::new();
+
+    for (lineno, line) in text.lines().enumerate().skip(1) {
+        let err = || format!("line {}: invalid timing", lineno + 1);
+
+        let fields = line.split(',').collect::<Vec<_>>();
+
+        let [line_os, day, step, runs, min_ns, median_ns, mean_ns] = fields.as_slice() else {
+            Err(err())?
+        };
+
+        let num = |s: &str| s.parse::<u64>().map_err(|_| err());
+
+        os = line_os.to_string();
+
+        timings.push(Timing {
+            day: num(day)? as usize,
+            step: step.to_string(),
+            runs: num(runs)? as usize,
+            min_ns: num(min_ns)?,
+            median_ns: num(median_ns)?,
+            mean_ns: num(mean_ns)?,
+        });
+    }
+
+    Ok(BenchResults { os, timings })
+}
+
+/// Formats a time in nanoseconds with an appropriate unit
+pub fn format_ns(ns: u64) -> String {
+    match ns {
+        0..1_000 => format!("{ns}ns"),
+        1_000..1_000_000 => format!("{:.2}µs", ns as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.2}ms", ns as f64 / 1e6),
+        _ => format!("{:.2}s", ns as f64 / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests;
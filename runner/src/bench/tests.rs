@@ -0,0 +1,91 @@
+use super::*;
+
+#[test]
+fn test_from_csv() {
+    let csv = "\
+os,day,step,runs,min_ns,median_ns,mean_ns
+linux,1,parse,10,100,120,130
+linux,1,part1,10,2000,2100,2200
+";
+
+    let results = from_csv(csv).unwrap();
+
+    assert_eq!(results.os, "linux");
+    assert_eq!(results.timings.len(), 2);
+    assert_eq!(results.timings[1].step, "part1");
+    assert_eq!(results.timings[1].median_ns, 2100);
+
+    assert_eq!(
+        from_csv("os,day\nlinux,1,parse\n")
+            .err()
+            .unwrap()
+            .to_string(),
+        "line 2: invalid timing"
+    );
+    assert!(from_csv("os,day\nlinux,x,parse,10,1,1,1\n").is_err());
+}
+
+#[test]
+fn test_timing_stats() {
+    let micros = |times: &[u64]| times.iter().map(|&t| Duration::from_micros(t)).collect();
+
+    let timing = Timing::new(3, "part1", micros(&[5, 1, 3, 2, 4]));
+
+    assert_eq!(timing.runs, 5);
+    assert_eq!(timing.min_ns, 1000);
+    assert_eq!(timing.median_ns, 3000);
+    assert_eq!(timing.mean_ns, 3000);
+
+    // The median of an even number of runs is the upper middle time
+    let timing = Timing::new(3, "part1", micros(&[10, 1, 3, 2]));
+
+    assert_eq!(timing.min_ns, 1000);
+    assert_eq!(timing.median_ns, 3000);
+    assert_eq!(timing.mean_ns, 4000);
+}
+
+#[test]
+fn test_output() {
+    let results = BenchResults {
+        os: "linux".to_string(),
+        timings: vec![
+            Timing::new(16, "parse*", vec![Duration::from_nanos(1500)]),
+            Timing::new(16, "part1", vec![Duration::from_nanos(20)]),
+        ],
+    };
+
+    let csv = to_csv(&results);
+
+    assert_eq!(
+        csv,
+        "\
+os,day,step,runs,min_ns,median_ns,mean_ns
+linux,16,parse*,1,1500,1500,1500
+linux,16,part1,1,20,20,20
+"
+    );
+    assert_eq!(from_csv(&csv).unwrap(), results);
+
+    let json = serde_json::to_string_pretty(&results).unwrap();
+
+    assert!(json.contains("\"step\": \"parse*\""));
+    assert_eq!(
+        serde_json::from_str::<BenchResults>(&json).unwrap(),
+        results
+    );
+}
+
+#[test]
+fn test_bench_day() {
+    let timings = bench_day(DAYS[0], "3   4\n4   3\n", 3).unwrap();
+
+    let steps = timings.iter().map(|t| t.step.as_str()).collect::<Vec<_>>();
+
+    assert_eq!(steps, vec!["parse", "part1", "part2"]);
+    assert!(timings.iter().all(|t| t.day == 1 && t.runs == 3));
+
+    // Day 16 searches the maze while parsing
+    let timings = bench_day(DAYS[15], "#####\n#..E#\n#.#.#\n#S..#\n#####\n", 1).unwrap();
+
+    assert_eq!(timings[0].step, "parse*");
+}
@@ -0,0 +1,185 @@
+use std::error::Error;
+
+use fxhash::FxHashMap;
+
+use crate::bench::{BenchResults, format_ns};
+
+/// Compare options
+pub struct CompareOptions {
+    /// Percentage change below which a difference is treated as noise
+    pub threshold: f64,
+    /// Percentage slowdown above which a regression fails the comparison
+    pub max_regression: f64,
+    /// Don't fail when steps in the old results are missing from the new results
+    pub allow_missing: bool,
+}
+
+impl CompareOptions {
+    /// Parses compare options from command line arguments
+    pub fn parse(args: &[&str]) -> Result<Self, Box<dyn Error>> {
+        let mut options = Self {
+            threshold: 5.0,
+            max_regression: 10.0,
+            allow_missing: false,
+        };
+
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || -> Result<f64, Box<dyn Error>> {
+                let value = args.next().ok_or(format!("No value given for {arg}"))?;
+
+                match value.trim_end_matches('%').parse::<f64>() {
+                    Ok(pct) if pct >= 0.0 => Ok(pct),
+                    _ => Err(format!("Invalid percentage '{value}' for {arg}"))?,
+                }
+            };
+
+            match *arg {
+                "--threshold" => options.threshold = value()?,
+                "--max-regression" => options.max_regression = value()?,
+                "--allow-missing" => options.allow_missing = true,
+                _ => Err(format!("Unknown option '{arg}'"))?,
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// Compares the median times in two benchmark result files. Fails if any step regressed,
+/// or if steps from the old file are missing from the new one unless allowed
+pub fn compare(old: &str, new: &str, options: CompareOptions) -> Result<(), Box<dyn Error>> {
+    let old_results = BenchResults::load(old)?;
+    let new_results = BenchResults::load(new)?;
+
+    println!(
+        "{:>3} {:<6} {:>12} {:>12} {:>9}",
+        "Day", "Step", "Old", "New", "Change"
+    );
+
+    let report = compare_results(&old_results, &new_results, &options);
+
+    for line in &report.lines {
+        println!("{line}");
+    }
+
+    if report.missing > 0 {
+        println!("{} step(s) missing from {new}", report.missing);
+    }
+
+    if report.regressions > 0 {
+        Err(format!(
+            "{} step(s) regressed by more than {}%",
+            report.regressions, options.max_regression
+        ))?
+    }
+
+    if report.missing > 0 && !options.allow_missing {
+        Err(format!(
+            "{} step(s) missing from {new}, use --allow-missing to ignore",
+            report.missing
+        ))?
+    }
+
+    Ok(())
+}
+
+/// Comparison of two sets of benchmark results
+struct Report {
+    /// Report line for each step
+    lines: Vec<String>,
+    /// Number of steps slower by more than the maximum regression
+    regressions: usize,
+    /// Number of steps in the old results only
+    missing: usize,
+}
+
+/// Compares the median time of each step in two sets of benchmark results
+fn compare_results(old: &BenchResults, new: &BenchResults, options: &CompareOptions) -> Report {
+    // Index timings by day and step
+    let index = |results: &BenchResults| {
+        results
+            .timings
+            .iter()
+            .map(|t| ((t.day, t.step.clone()), t.median_ns))
+            .collect::<FxHashMap<_, _>>()
+    };
+
+    let old_timings = index(old);
+    let new_timings = index(new);
+
+    let mut report = Report {
+        lines: Vec::new(),
+        regressions: 0,
+        missing: 0,
+    };
+
+    for t in &new.timings {
+        let Some(&old_ns) = old_timings.get(&(t.day, t.step.clone())) else {
+            report.lines.push(format!(
+                "{:>3} {:<6} {:>12} {:>12} {:>9} new",
+                t.day,
+                t.step,
+                "-",
+                format_ns(t.median_ns),
+                ""
+            ));
+            continue;
+        };
+
+        let change = percent_change(old_ns, t.median_ns);
+
+        let note = if change > options.max_regression {
+            report.regressions += 1;
+            "REGRESSION"
+        } else if change.abs() < options.threshold {
+            ""
+        } else if change < 0.0 {
+            "faster"
+        } else {
+            "slower"
+        };
+
+        let line = format!(
+            "{:>3} {:<6} {:>12} {:>12} {:>+8.1}% {note}",
+            t.day,
+            t.step,
+            format_ns(old_ns),
+            format_ns(t.median_ns),
+            change
+        );
+
+        report.lines.push(line.trim_end().to_string());
+    }
+
+    // Steps which have gone, or failed in the new run
+    for t in &old.timings {
+        if !new_timings.contains_key(&(t.day, t.step.clone())) {
+            report.missing += 1;
+            report.lines.push(format!(
+                "{:>3} {:<6} {:>12} {:>12} {:>9} missing",
+                t.day,
+                t.step,
+                format_ns(t.median_ns),
+                "-",
+                ""
+            ));
+        }
+    }
+
+    report
+}
+
+/// Returns the percentage change from an old time to a new one. Any slowdown from a zero
+/// time is an infinite increase
+fn percent_change(old_ns: u64, new_ns: u64) -> f64 {
+    match (old_ns, new_ns) {
+        (0, 0) => 0.0,
+        (0, _) => f64::INFINITY,
+        _ => (new_ns as f64 - old_ns as f64) * 100.0 / old_ns as f64,
+    }
+}
+
+#[cfg(test)]
+mod tests;
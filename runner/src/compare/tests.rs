@@ -0,0 +1,117 @@
+use super::*;
+use crate::bench::Timing;
+
+fn results(timings: &[(usize, &str, u64)]) -> BenchResults {
+    BenchResults {
+        os: "linux".to_string(),
+        timings: timings
+            .iter()
+            .map(|&(day, step, median_ns)| Timing {
+                day,
+                step: step.to_string(),
+                runs: 10,
+                min_ns: median_ns,
+                median_ns,
+                mean_ns: median_ns,
+            })
+            .collect(),
+    }
+}
+
+fn options() -> CompareOptions {
+    CompareOptions::parse(&["--threshold", "5", "--max-regression", "10%"]).unwrap()
+}
+
+#[test]
+fn test_percent_change() {
+    assert_eq!(percent_change(100, 150), 50.0);
+    assert_eq!(percent_change(200, 100), -50.0);
+    assert_eq!(percent_change(0, 0), 0.0);
+    assert_eq!(percent_change(0, 10), f64::INFINITY);
+}
+
+#[test]
+fn test_compare_results() {
+    let old = results(&[
+        (1, "parse", 1000),
+        (1, "part1", 1000),
+        (1, "part2", 1000),
+        (2, "parse", 0),
+        (2, "part1", 500),
+    ]);
+    let new = results(&[
+        (1, "parse", 1040),
+        (1, "part1", 1100),
+        (1, "part2", 1101),
+        (2, "parse", 10),
+        (3, "parse", 100),
+    ]);
+
+    let report = compare_results(&old, &new, &options());
+
+    let notes = report
+        .lines
+        .iter()
+        .map(|l| l.split_whitespace().last().unwrap())
+        .collect::<Vec<_>>();
+
+    // Within the threshold, slower, over the maximum, slower than a zero time, new, missing
+    assert_eq!(
+        notes,
+        vec![
+            "+4.0%",
+            "slower",
+            "REGRESSION",
+            "REGRESSION",
+            "new",
+            "missing"
+        ]
+    );
+    assert_eq!(report.regressions, 2);
+    assert_eq!(report.missing, 1);
+    assert!(report.lines[5].starts_with("  2 part1"));
+}
+
+#[test]
+fn test_parse_options() {
+    let options = options();
+
+    assert_eq!(options.threshold, 5.0);
+    assert_eq!(options.max_regression, 10.0);
+    assert!(!options.allow_missing);
+
+    assert!(
+        CompareOptions::parse(&["--allow-missing"])
+            .unwrap()
+            .allow_missing
+    );
+
+    assert!(CompareOptions::parse(&["--threshold", "-1"]).is_err());
+    assert!(CompareOptions::parse(&["--threshold"]).is_err());
+    assert!(CompareOptions::parse(&["--fast"]).is_err());
+}
+
+#[test]
+fn test_compare_missing() {
+    let dir = std::env::temp_dir();
+    let save = |name: &str, timings: &[(usize, &str, u64)]| {
+        let file = dir.join(format!("aoc-compare-{}-{name}.json", std::process::id()));
+        std::fs::write(&file, serde_json::to_string(&results(timings)).unwrap()).unwrap();
+        file.to_str().unwrap().to_string()
+    };
+
+    let old = save("old", &[(1, "parse", 1000), (1, "part1", 1000)]);
+    let new = save("new", &[(1, "parse", 1000)]);
+
+    // Steps missing from the new results fail unless allowed
+    assert!(compare(&old, &new, options()).is_err());
+
+    let allow = CompareOptions::parse(&["--allow-missing"]).unwrap();
+    assert!(compare(&old, &new, allow).is_ok());
+
+    // New steps are fine
+    assert!(compare(&new, &old, options()).is_ok());
+
+    std::fs::remove_file(old).unwrap();
+    std::fs::remove_file(new).unwrap();
+}
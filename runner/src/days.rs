@@ -0,0 +1,30 @@
+use aoc::solution::DaySolution;
+
+/// Table of solutions indexed by day number - 1
+pub const DAYS: [&dyn DaySolution; 25] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11::INPUT,
+    &day12::Day12,
+    &day13::Day13::INPUT,
+    &day14::Day14::INPUT,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18::INPUT,
+    &day19::Day19,
+    &day20::Day20::INPUT,
+    &day21::Day21::INPUT,
+    &day22::Day22::INPUT,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
@@ -0,0 +1,263 @@
+use std::{error::Error, ops::RangeInclusive};
+
+use aoc::{
+    answers::Verdict,
+    input::InputSource,
+    params::{DayOverride, Override, load_config_days, parse_day_override},
+    solution::{Answer, DaySolution},
+};
+
+mod bench;
+use bench::{BenchOptions, bench};
+
+mod compare;
+use compare::{CompareOptions, compare};
+
+mod days;
+use days::DAYS;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    // Get command line arguments
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(|a| a.as_str()).collect::<Vec<_>>();
+
+    // Process command
+    match args.as_slice() {
+        ["run", days, options @ ..] => run(parse_days(days)?, RunOptions::parse(options)?),
+        ["verify", days] => verify(parse_days(days)?),
+        ["bench", days, options @ ..] => bench(parse_days(days)?, BenchOptions::parse(options)?),
+        ["compare", old, new, options @ ..] => compare(old, new, CompareOptions::parse(options)?),
+        _ => {
+            usage();
+            Err("Invalid arguments")?
+        }
+    }
+}
+
+fn usage() {
+    eprintln!(
+        "Usage: aoc run <days> [--input <file>] [--param [day:]<name=value>]... [--config <file>]"
+    );
+    eprintln!("       aoc verify <days>");
+    eprintln!("       aoc bench <days> [--runs <n>] [--json <file>] [--csv <file>]");
+    eprintln!("       aoc compare <old> <new> [--threshold <pct>] [--max-regression <pct>]");
+    eprintln!("                   [--allow-missing]");
+    eprintln!();
+    eprintln!("  <days> is one of:");
+    eprintln!("    all      all days");
+    eprintln!("    n        a single day");
+    eprintln!("    n..m     days n up to but not including m");
+    eprintln!("    n..=m    days n to m inclusive");
+    eprintln!();
+    eprintln!("  run reads inputs/dayNN.txt, or dayNN.txt in $AOC_INPUT_DIR if set.");
+    eprintln!("  --input reads a single day's input from a file instead (- for stdin)");
+    eprintln!("  --param overrides a puzzle parameter such as a grid size, and --config");
+    eprintln!("  reads name = value overrides from a file, in [dayNN] sections to apply");
+    eprintln!("  them to one day only. A day: prefix limits a --param to that day. When");
+    eprintln!("  running several days, parameters not limited to a day are skipped for");
+    eprintln!("  days without them");
+    eprintln!();
+    eprintln!("  verify checks the answers against answers/dayNN.toml");
+    eprintln!();
+    eprintln!("  bench times parse, part 1 and part 2 separately over a number");
+    eprintln!("  of runs (default 10) and writes the results as JSON or CSV");
+    eprintln!("  (default stats/bench-<os>.json). parse* marks a parse step which also");
+    eprintln!("  does solving work shared by both parts");
+    eprintln!();
+    eprintln!("  compare shows the change in median time for each step between two");
+    eprintln!("  bench result files. Changes below the threshold (default 5%) are");
+    eprintln!("  treated as noise. Fails if any step is slower by more than the");
+    eprintln!("  maximum regression (default 10%). Any slowdown from a time of zero is a");
+    eprintln!("  regression. Steps only in the old file are listed as missing and fail");
+    eprintln!("  the comparison unless --allow-missing is given. Steps only in the new");
+    eprintln!("  file are listed as new");
+}
+
+/// Options for the run command
+struct RunOptions {
+    /// Input to use instead of the day's input file
+    input: Option<InputSource<'static>>,
+    /// Config file of parameter overrides
+    config: Option<String>,
+    /// Parameter overrides from the command line
+    params: Vec<DayOverride>,
+}
+
+impl RunOptions {
+    /// Parses run options from command line arguments
+    fn parse(args: &[&str]) -> Result<Self, Box<dyn Error>> {
+        let mut options = Self {
+            input: None,
+            config: None,
+            params: Vec::new(),
+        };
+
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("No value given for {arg}"));
+
+            match *arg {
+                "--input" => options.input = Some(InputSource::from_arg(value()?)),
+                "--config" => options.config = Some(value()?.to_string()),
+                "--param" => options.params.push(parse_day_override(value()?)?),
+                _ => Err(format!("Unknown option '{arg}'"))?,
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Returns the parameter overrides for a day's solution. Command line parameters
+    /// override the config file. Unless strict, overrides not limited to a day are skipped
+    /// if the solution has no parameter with that name
+    fn overrides(
+        &self,
+        solution: &dyn DaySolution,
+        strict: bool,
+    ) -> Result<Vec<Override>, Box<dyn Error>> {
+        let mut overrides = match &self.config {
+            Some(file) => load_config_days(file)?,
+            None => Vec::new(),
+        };
+
+        overrides.extend(self.params.iter().cloned());
+
+        let names = solution.params();
+
+        Ok(overrides
+            .into_iter()
+            .filter(|(day, (name, _))| match day {
+                Some(day) => *day == solution.day(),
+                None => strict || names.iter().any(|(n, _)| n == name),
+            })
+            .map(|(_, o)| o)
+            .collect())
+    }
+}
+
+fn run(days: RangeInclusive<usize>, options: RunOptions) -> Result<(), Box<dyn Error>> {
+    if options.input.is_some() && days.start() != days.end() {
+        Err("An input file can only be given for a single day")?
+    }
+
+    let mut failed = 0;
+
+    // Unknown parameters are only errors when running a single day
+    let strict = days.start() == days.end();
+
+    for day in days {
+        println!("Day {day}:");
+
+        // Run the solution for the day with any parameters overridden
+        let source = options.input.clone().unwrap_or(InputSource::Day(day));
+
+        let result = options
+            .overrides(DAYS[day - 1], strict)
+            .and_then(|overrides| DAYS[day - 1].with_params(&overrides))
+            .and_then(|solution| aoc::solution::run_source(solution.as_ref(), source));
+
+        if let Err(e) = result {
+            println!("Error: {e}");
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        Err(format!("{failed} day(s) failed"))?
+    }
+
+    Ok(())
+}
+
+fn verify(days: RangeInclusive<usize>) -> Result<(), Box<dyn Error>> {
+    let mut tally = VerifyTally::default();
+
+    for day in days {
+        // Solve the day and check against stored answers
+        tally.add(day, aoc::answers::verify(DAYS[day - 1]));
+    }
+
+    if tally.missing > 0 {
+        println!("{} answer(s) missing", tally.missing);
+    }
+
+    if tally.missing_inputs > 0 {
+        println!("{} input(s) missing", tally.missing_inputs);
+    }
+
+    if tally.failed > 0 {
+        Err(format!("{} check(s) failed", tally.failed))?
+    }
+
+    Ok(())
+}
+
+/// Counts of verify outcomes
+#[derive(Debug, Default, PartialEq, Eq)]
+struct VerifyTally {
+    failed: usize,
+    missing: usize,
+    missing_inputs: usize,
+}
+
+impl VerifyTally {
+    /// Prints and counts the outcome of verifying a day. A day without an input file is
+    /// counted as a missing input rather than a failure
+    fn add(&mut self, day: usize, result: Result<Vec<(Answer, Verdict)>, Box<dyn Error>>) {
+        match result {
+            Ok(results) => {
+                for (part, (answer, verdict)) in results.iter().enumerate() {
+                    println!("Day {day} part {}: {answer}: {verdict}", part + 1);
+
+                    match verdict {
+                        Verdict::Pass => (),
+                        Verdict::Fail(_) => self.failed += 1,
+                        Verdict::Missing => self.missing += 1,
+                    }
+                }
+            }
+            Err(e) if matches!(e.downcast_ref(), Some(aoc::Error::MissingFile(_))) => {
+                println!("Day {day}: Missing input: {e}");
+                self.missing_inputs += 1;
+            }
+            Err(e) => {
+                println!("Day {day}: Error: {e}");
+                self.failed += 1;
+            }
+        }
+    }
+}
+
+/// Parses a day specification to a range of days
+fn parse_days(spec: &str) -> Result<RangeInclusive<usize>, Box<dyn Error>> {
+    let parse_bounded = |day: &str, last: usize| -> Result<usize, Box<dyn Error>> {
+        match day.parse::<usize>() {
+            Ok(day) if (1..=last).contains(&day) => Ok(day),
+            _ => Err(format!("Invalid day '{day}'"))?,
+        }
+    };
+
+    let parse_day = |day: &str| parse_bounded(day, DAYS.len());
+
+    let range = if spec == "all" {
+        1..=DAYS.len()
+    } else if let Some((from, to)) = spec.split_once("..=") {
+        parse_day(from)?..=parse_day(to)?
+    } else if let Some((from, to)) = spec.split_once("..") {
+        // The exclusive end can be one past the last day
+        parse_day(from)?..=(parse_bounded(to, DAYS.len() + 1)? - 1)
+    } else {
+        let day = parse_day(spec)?;
+        day..=day
+    };
+
+    if range.is_empty() {
+        Err(format!("Day range '{spec}' is empty"))?
+    }
+
+    Ok(range)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,94 @@
+use super::*;
+
+fn owned(overrides: &[(&str, &str)]) -> Vec<Override> {
+    overrides
+        .iter()
+        .map(|(n, v)| (n.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_run_overrides() {
+    let options = RunOptions::parse(&["--param", "dim=6", "--param", "18:bytes=12"]).unwrap();
+
+    // Several days skip parameters a day doesn't have
+    assert_eq!(options.overrides(DAYS[16], false).unwrap(), vec![]);
+    assert_eq!(
+        options.overrides(DAYS[17], false).unwrap(),
+        owned(&[("dim", "6"), ("bytes", "12")])
+    );
+    assert!(DAYS[16].with_params(&[]).is_ok());
+
+    // A single day reports them
+    let overrides = options.overrides(DAYS[16], true).unwrap();
+
+    assert_eq!(overrides, owned(&[("dim", "6")]));
+    assert_eq!(
+        DAYS[16].with_params(&overrides).err().unwrap().to_string(),
+        "Unknown parameter 'dim'"
+    );
+
+    // Parameters limited to a day are kept for that day
+    let options = RunOptions::parse(&["--param", "17:dim=6"]).unwrap();
+
+    assert_eq!(
+        options.overrides(DAYS[16], false).unwrap(),
+        owned(&[("dim", "6")])
+    );
+    assert_eq!(options.overrides(DAYS[17], true).unwrap(), vec![]);
+}
+
+#[test]
+fn test_run_options_errors() {
+    assert!(RunOptions::parse(&["--param"]).is_err());
+    assert!(RunOptions::parse(&["--param", "x:dim=6"]).is_err());
+    assert!(RunOptions::parse(&["--fast"]).is_err());
+}
+
+#[test]
+fn test_verify_tally() {
+    let mut tally = VerifyTally::default();
+
+    tally.add(
+        1,
+        Ok(vec![
+            (Answer::Int(1), Verdict::Pass),
+            (Answer::Int(2), Verdict::Missing),
+        ]),
+    );
+    tally.add(2, Ok(vec![(Answer::Int(1), Verdict::Fail(Answer::Int(3)))]));
+
+    // A missing input is counted separately from failures
+    tally.add(
+        3,
+        Err(aoc::Error::MissingFile("inputs/day03.txt".into()).into()),
+    );
+    tally.add(4, Err("No route from S to E".into()));
+
+    assert_eq!(
+        tally,
+        VerifyTally {
+            failed: 2,
+            missing: 1,
+            missing_inputs: 1,
+        }
+    );
+}
+
+#[test]
+fn test_parse_days() {
+    assert_eq!(parse_days("all").unwrap(), 1..=25);
+    assert_eq!(parse_days("16").unwrap(), 16..=16);
+    assert_eq!(parse_days("3..=7").unwrap(), 3..=7);
+    assert_eq!(parse_days("3..7").unwrap(), 3..=6);
+
+    // The exclusive end can be one past the last day
+    assert_eq!(parse_days("1..26").unwrap(), 1..=25);
+
+    assert!(parse_days("0").is_err());
+    assert!(parse_days("26").is_err());
+    assert!(parse_days("1..=26").is_err());
+    assert!(parse_days("1..27").is_err());
+    assert!(parse_days("7..7").is_err());
+    assert!(parse_days("x").is_err());
+}
@@ -0,0 +1,51 @@
+use std::error::Error;
+
+use aoc::{
+    input::{InputSource, parse_input_vec},
+    solution::{Answer, Solution},
+};
+
+/// Day $day solution
+#[derive(Clone)]
+pub struct Day$daypad;
+
+impl Solution for Day$daypad {
+    const DAY: usize = $day;
+
+    type Input = Vec<InputEnt>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_input_vec(InputSource::Str(input), input_transform)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
+
+/// Calculates the part 1 answer
+pub fn part1(input: &[InputEnt]) -> u64 {
+    0 // TODO
+}
+
+/// Calculates the part 2 answer
+pub fn part2(input: &[InputEnt]) -> u64 {
+    0 // TODO
+}
+
+// Input parsing
+
+pub type InputEnt = String; // TODO
+
+/// Parses an input line
+pub fn input_transform(line: &str) -> InputEnt {
+    // TODO
+    line.to_string()
+}
+
+#[cfg(test)]
+mod tests;